@@ -0,0 +1,68 @@
+//! Captures the resolved versions of the direct dependencies from Cargo.lock,
+//! so that each library adapter can read its version as `VERSION_<PACKAGE>`,
+//! and the version of the compiler which provides the f64 baseline.
+
+use std::{env, fs, path::Path, process::Command};
+
+fn main() {
+    let lock = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.lock");
+    println!("cargo:rerun-if-changed={}", lock.display());
+
+    let content = fs::read_to_string(&lock).unwrap_or_default();
+    let packages = locked_packages(&content);
+    let root = env::var("CARGO_PKG_NAME").unwrap();
+    let deps = packages.iter().find(|p| p.name == root).map_or(&[][..], |p| &p.dependencies[..]);
+    for dep in deps {
+        // a dependency which is locked in several versions is listed with its version
+        let mut words = dep.split_whitespace();
+        let name = words.next().unwrap();
+        let version = match words.next() {
+            Some(version) => version,
+            None => packages.iter().find(|p| p.name == name).map_or("unknown", |p| p.version),
+        };
+        println!("cargo:rustc-env=VERSION_{}={}", name.to_uppercase().replace('-', "_"), version);
+    }
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .and_then(|s| s.split_whitespace().nth(1).map(|v| v.to_string()))
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=VERSION_RUSTC={}", version);
+}
+
+/// Package entry of Cargo.lock.
+struct Package<'a> {
+    name: &'a str,
+    version: &'a str,
+    dependencies: Vec<&'a str>,
+}
+
+/// Returns the packages of Cargo.lock.
+fn locked_packages(lock: &str) -> Vec<Package<'_>> {
+    lock.split("[[package]]")
+        .skip(1)
+        .map(|block| {
+            let field = |key: &str| {
+                block
+                    .lines()
+                    .find_map(|l| l.strip_prefix(key)?.strip_prefix(" = \"")?.strip_suffix('"'))
+                    .unwrap_or("")
+            };
+            let dependencies = match block.split_once("dependencies = [") {
+                Some((_, rest)) => rest
+                    .split(']')
+                    .next()
+                    .unwrap()
+                    .lines()
+                    .filter_map(|l| l.trim().strip_prefix('"')?.strip_suffix("\","))
+                    .collect(),
+                None => vec![],
+            };
+            Package { name: field("name"), version: field("version"), dependencies }
+        })
+        .collect()
+}
@@ -1,47 +1,99 @@
-//! Wrapper for astro_float::BigFloat
+//! Wrapper for astro_float::BigFloat and its adapter.
+//!
+//! Operations take the precision, the rounding mode and the constants cache
+//! from an astro-float `Context` shared by all numbers of a task.
 
 use std::{
     fmt::Display,
     ops::{Add, Div, Mul, Sub},
-    cell::RefCell,
+    cell::{Ref, RefCell, RefMut},
     rc::Rc,
 };
-use astro_float::{BigFloat, Consts, Sign};
-use astro_float::Exponent;
+use astro_float::{BigFloat, Consts};
+use astro_float::ctx::Context;
 use astro_float::RoundingMode;
+#[cfg(feature = "rug")]
+use rug::{Float, Integer, integer::Order};
+use crate::number::{BinaryOp, GlobalState, Number, Parts, UnaryOp};
+use crate::registry::Backend;
+use crate::report::Outcome;
+use crate::Options;
 
-pub struct AstroFloat
-{
-    af: BigFloat,
-    pub cc: Rc<RefCell<Consts>>,
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_ASTRO_FLOAT");
+
+inventory::submit! {
+    Backend::new::<AstroGlobalState, AstroFloat>("astro-float", VERSION)
 }
 
+inventory::submit! {
+    Backend { run: benchmark_cold, ..Backend::new::<AstroGlobalState, AstroFloat>("astro-float-cold", VERSION) }
+}
 
-impl AstroFloat {
+/// Context of operations shared by numbers.
+/// If `fresh` is set the constants cache of the context is recreated for every operation,
+/// so each operation computes the constants it needs from scratch.
+pub struct AstroContext {
+    ctx: RefCell<Context>,
+    fresh: bool,
+}
+
+impl AstroContext {
 
-    pub fn random_normal(exp_from: Exponent, exp_to: Exponent, cc: Rc<RefCell<Consts>>, sign_positive: bool) -> Self {
-        let exp_from = (exp_from as i64 * 3321928095 / 1000000000) as Exponent;
-        let exp_to = (exp_to as i64 * 3321928095 / 1000000000) as Exponent;
-        let mut af = BigFloat::random_normal(132, exp_from, exp_to);
-        if sign_positive {
-            af.set_sign(Sign::Pos);
+    pub fn new(p: usize, fresh: bool) -> Self {
+        AstroContext {
+            ctx: RefCell::new(Context::new(p, RoundingMode::ToEven, Consts::new().unwrap())),
+            fresh,
         }
-        AstroFloat {
-            af,
-            cc,
+    }
+
+    /// Returns the context for an operation which doesn't use constants.
+    pub fn get(&self) -> Ref<'_, Context> {
+        self.ctx.borrow()
+    }
+
+    /// Returns the context for an operation which uses constants.
+    pub fn get_with_consts(&self) -> RefMut<'_, Context> {
+        let mut ctx = self.ctx.borrow_mut();
+        if self.fresh {
+            ctx.set_consts(Consts::new().unwrap());
         }
+        ctx
     }
+}
+
+pub struct AstroFloat
+{
+    af: BigFloat,
+    pub ctx: Rc<AstroContext>,
+}
+
+
+impl AstroFloat {
 
-    pub fn new(f: BigFloat, cc: Rc<RefCell<Consts>>) -> Self {
+    pub fn new(f: BigFloat, ctx: Rc<AstroContext>) -> Self {
         AstroFloat {
             af: f,
-            cc,
+            ctx,
         }
     }
 
     pub fn inner(&self) -> &BigFloat {
         &self.af
     }
+
+    /// Applies an operation which doesn't use constants with the precision and the rounding mode of the context.
+    fn apply(&self, op: impl FnOnce(&BigFloat, usize, RoundingMode) -> BigFloat) -> Self {
+        let ctx = self.ctx.get();
+        AstroFloat::new(op(&self.af, ctx.precision(), ctx.rounding_mode()), self.ctx.clone())
+    }
+
+    /// Applies an operation with the precision, the rounding mode and the constants cache of the context.
+    fn apply_with_consts(&self, op: impl FnOnce(&BigFloat, usize, RoundingMode, &mut Consts) -> BigFloat) -> Self {
+        let mut ctx = self.ctx.get_with_consts();
+        let (p, rm) = (ctx.precision(), ctx.rounding_mode());
+        AstroFloat::new(op(&self.af, p, rm, ctx.consts()), self.ctx.clone())
+    }
 }
 
 
@@ -55,7 +107,7 @@ impl Clone for AstroFloat {
     fn clone(&self) -> Self {
         Self {
             af: self.af.clone(),
-            cc: self.cc.clone(),
+            ctx: self.ctx.clone(),
         }
     }
 }
@@ -64,10 +116,7 @@ impl Add<Self> for AstroFloat {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.add(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.add(&rhs.af, p, rm))
     }
 }
 
@@ -75,10 +124,7 @@ impl<'a> Add<&'a Self> for AstroFloat {
     type Output = Self;
 
     fn add(self, rhs: &'a Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.add(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.add(&rhs.af, p, rm))
     }
 }
 
@@ -86,10 +132,7 @@ impl Sub<Self> for AstroFloat {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.sub(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.sub(&rhs.af, p, rm))
     }
 }
 
@@ -97,10 +140,7 @@ impl<'a> Sub<&'a Self> for AstroFloat {
     type Output = Self;
 
     fn sub(self, rhs: &'a Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.sub(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.sub(&rhs.af, p, rm))
     }
 }
 
@@ -108,10 +148,7 @@ impl Mul<Self> for AstroFloat {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.mul(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.mul(&rhs.af, p, rm))
     }
 }
 
@@ -119,10 +156,7 @@ impl<'a> Mul<&'a Self> for AstroFloat {
     type Output = Self;
 
     fn mul(self, rhs: &'a Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.mul(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.mul(&rhs.af, p, rm))
     }
 }
 
@@ -130,10 +164,7 @@ impl Div<Self> for AstroFloat {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.div(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
-        }
+        self.apply(|x, p, rm| x.div(&rhs.af, p, rm))
     }
 }
 
@@ -141,10 +172,111 @@ impl<'a> Div<&'a Self> for AstroFloat {
     type Output = Self;
 
     fn div(self, rhs: &'a Self) -> Self::Output {
-        AstroFloat {
-            af: self.af.div(&rhs.af, self.af.mantissa_max_bit_len().unwrap_or(1), RoundingMode::ToEven),
-            cc: self.cc
+        self.apply(|x, p, rm| x.div(&rhs.af, p, rm))
+    }
+}
+
+pub struct AstroGlobalState {
+    ctx: Rc<AstroContext>,
+}
+
+impl AstroGlobalState {
+    /// Creates a state with the constants cache recreated for every operation.
+    pub fn with_fresh_consts(p: usize) -> Self {
+        AstroGlobalState {
+            ctx: Rc::new(AstroContext::new(p, true)),
         }
     }
 }
 
+impl GlobalState for AstroGlobalState {
+    fn precision(&self) -> usize {
+        self.ctx.get().precision()
+    }
+}
+
+/// Runs the task with the constants cache recreated for every operation.
+fn benchmark_cold(task: &str, corpus: &[Parts], p: usize, opts: &Options) -> Outcome {
+    crate::benchmark_lib_task_in::<AstroGlobalState, AstroFloat>(task, corpus, AstroGlobalState::with_fresh_consts(p), opts)
+}
+
+impl Number<AstroGlobalState> for AstroFloat {
+
+    fn from_parts(parts: &Parts, gs: &AstroGlobalState) -> Self {
+        let sign = if parts.negative { astro_float::Sign::Neg } else { astro_float::Sign::Pos };
+        AstroFloat::new(BigFloat::from_words(&parts.mantissa, sign, parts.exp), gs.ctx.clone())
+    }
+
+    fn global_state(p: usize) -> AstroGlobalState {
+        AstroGlobalState {
+            ctx: Rc::new(AstroContext::new(p, false)),
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        let f = self.inner();
+        if f.is_nan() {
+            Float::with_val(p, rug::float::Special::Nan)
+        } else if f.is_inf_pos() {
+            Float::with_val(p, rug::float::Special::Infinity)
+        } else if f.is_inf_neg() {
+            Float::with_val(p, rug::float::Special::NegInfinity)
+        } else {
+            let (mantissa, _, sign, exp, _) = f.as_raw_parts().unwrap();
+            let mut ret = Float::with_val(p, Integer::from_digits(mantissa, Order::Lsf));
+            ret <<= exp as isize - (mantissa.len() * 64) as isize;
+            if sign == astro_float::Sign::Neg { -ret } else { ret }
+        }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        self.apply(|x, p, rm| x.add(rhs.inner(), p, rm))
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self.apply(|x, p, rm| x.sub(rhs.inner(), p, rm))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.apply(|x, p, rm| x.mul(rhs.inner(), p, rm))
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.apply(|x, p, rm| x.div(rhs.inner(), p, rm))
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| x.apply(|x, p, rm| x.sqrt(p, rm)));
+
+    const CBRT: Option<UnaryOp<Self>> = Some(|x| x.apply(|x, p, rm| x.cbrt(p, rm)));
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.ln(p, rm, cc)));
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.exp(p, rm, cc)));
+
+    const POW: Option<BinaryOp<Self>> = Some(|x, n| x.apply_with_consts(|x, p, rm, cc| x.pow(n.inner(), p, rm, cc)));
+
+    const SIN: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.sin(p, rm, cc)));
+
+    const ASIN: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.asin(p, rm, cc)));
+
+    const COS: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.cos(p, rm, cc)));
+
+    const ACOS: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.acos(p, rm, cc)));
+
+    const TAN: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.tan(p, rm, cc)));
+
+    const ATAN: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.atan(p, rm, cc)));
+
+    const SINH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.sinh(p, rm, cc)));
+
+    const ASINH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.asinh(p, rm, cc)));
+
+    const COSH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.cosh(p, rm, cc)));
+
+    const ACOSH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.acosh(p, rm, cc)));
+
+    const TANH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.tanh(p, rm, cc)));
+
+    const ATANH: Option<UnaryOp<Self>> = Some(|x| x.apply_with_consts(|x, p, rm, cc| x.atanh(p, rm, cc)));
+}
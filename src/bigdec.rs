@@ -0,0 +1,153 @@
+//! Adapter for bigdecimal.
+
+use std::fmt::Display;
+use std::num::NonZeroU64;
+use bigdecimal::num_bigint::{BigInt, BigUint, Sign};
+use bigdecimal::{BigDecimal, Context, Pow, RoundingMode};
+#[cfg(feature = "rug")]
+use rug::{Float, Integer, integer::Order};
+use crate::number::{GlobalState, Number, Parts, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_BIGDECIMAL");
+
+inventory::submit! {
+    Backend::new::<DecimalGlobalState, BigDec>("bigdecimal", VERSION)
+}
+
+/// Returns the number of decimal digits which is at least as precise as `p` bits.
+fn decimal_digits(p: usize) -> u64 {
+    (p as f64 * std::f64::consts::LOG10_2).ceil() as u64
+}
+
+pub struct DecimalGlobalState {
+    ctx: Context,
+    p: usize,
+}
+
+impl GlobalState for DecimalGlobalState {
+    fn precision(&self) -> usize {
+        self.p
+    }
+}
+
+/// Wrapper for BigDecimal which keeps the context of operations.
+#[derive(Clone)]
+pub struct BigDec {
+    d: BigDecimal,
+    ctx: Context,
+}
+
+impl BigDec {
+    fn new(d: BigDecimal, ctx: &Context) -> Self {
+        BigDec { d, ctx: ctx.clone() }
+    }
+}
+
+impl Display for BigDec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.d)
+    }
+}
+
+impl Number<DecimalGlobalState> for BigDec {
+    fn from_parts(parts: &Parts, gs: &DecimalGlobalState) -> Self {
+        let digits: Vec<u32> = parts.mantissa.iter().flat_map(|w| [*w as u32, (w >> 32) as u32]).collect();
+        let sign = if parts.negative { Sign::Minus } else { Sign::Plus };
+        let m = BigInt::from_biguint(sign, BigUint::new(digits));
+
+        // m * 2^e is exactly m * 5^-e / 10^-e for negative e
+        let e = parts.int_exp();
+        let d = if e >= 0 {
+            BigDecimal::new(m << e as usize, 0)
+        } else {
+            BigDecimal::new(m * BigInt::from(5).pow(e.unsigned_abs()), -e as i64)
+        };
+        BigDec::new(gs.ctx.round_decimal(d), &gs.ctx)
+    }
+
+    fn global_state(p: usize) -> DecimalGlobalState {
+        let digits = NonZeroU64::new(decimal_digits(p).max(1)).unwrap();
+        DecimalGlobalState {
+            ctx: Context::new(digits, RoundingMode::HalfEven),
+            p,
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        let (i, scale) = self.d.as_bigint_and_exponent();
+        let (sign, words) = i.to_u64_digits();
+        let mut ret = Float::with_val(p, Integer::from_digits(&words, Order::Lsf));
+        let pow10 = Integer::from(Integer::u_pow_u(10, scale.unsigned_abs() as u32));
+        if scale >= 0 {
+            ret /= pow10;
+        } else {
+            ret *= pow10;
+        }
+        if sign == Sign::Minus { -ret } else { ret }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        BigDec::new(self.ctx.add_refs(&self.d, &rhs.d), &self.ctx)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        BigDec::new(self.ctx.add_refs(&self.d, -rhs.d.to_ref()), &self.ctx)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        BigDec::new(self.ctx.multiply(&self.d, &rhs.d), &self.ctx)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        // division has no context and always computes 100 digits, so the dividend is scaled
+        // for the integer quotient to have two digits more than the precision, which are then rounded
+        let (a, a_scale) = self.d.as_bigint_and_scale();
+        let (b, b_scale) = rhs.d.as_bigint_and_scale();
+        let k = (self.ctx.precision().get() as i64 + 2 + rhs.d.digits() as i64 - self.d.digits() as i64).max(0);
+        let q = a.as_ref() * BigInt::from(10).pow(k as u32) / b.as_ref();
+        BigDec::new(self.ctx.round_decimal(BigDecimal::new(q, a_scale - b_scale + k)), &self.ctx)
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| BigDec::new(x.d.sqrt_with_context(&x.ctx).unwrap(), &x.ctx));
+
+    const CBRT: Option<UnaryOp<Self>> = Some(|x| BigDec::new(x.d.cbrt_with_context(&x.ctx), &x.ctx));
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| BigDec::new(x.d.exp_with_context(&x.ctx), &x.ctx));
+}
+
+#[cfg(all(test, feature = "rug"))]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use super::*;
+
+    /// Returns random numbers with `p` bits, exponents from -10 to 10 with base 10,
+    /// rounded to the context of the precision `gs_p`.
+    fn operands(n: usize, p: usize, gs_p: usize) -> Vec<BigDec> {
+        let mut rng = StdRng::seed_from_u64((n + p) as u64);
+        let gs = BigDec::global_state(gs_p);
+        (0..n).map(|_| BigDec::from_parts(&Parts::random_normal(&mut rng, p, -10, 10, false), &gs)).collect()
+    }
+
+    #[test]
+    fn matches_rug() {
+        for (p, gs_p) in [(132, 132), (1000, 1000), (20, 132), (132, 1000)] {
+            // correctly rounded results are within half a unit in the last decimal place
+            let max_error = 0.5 * 10f64.powi(1 - decimal_digits(gs_p) as i32);
+            let rp = 4 * gs_p as u32;
+            let rel_error = |res: &BigDec, reference: Float| ((res.to_reference(rp) - &reference) / reference).abs().to_f64();
+            let v = operands(2000, p, gs_p);
+            let w = operands(1000, gs_p, gs_p);
+            for ((a, b), c) in v[..1000].iter().zip(&v[1000..]).zip(&w) {
+                let (ra, rb, rc) = (a.to_reference(rp), b.to_reference(rp), c.to_reference(rp));
+                assert!(rel_error(&a.add(c), Float::with_val(rp, &ra + &rc)) <= max_error, "{} + {}", a, c);
+                assert!(rel_error(&a.sub(c), Float::with_val(rp, &ra - &rc)) <= max_error, "{} - {}", a, c);
+                assert!(rel_error(&a.div(b), Float::with_val(rp, &ra / &rb)) <= max_error, "{} / {}", a, b);
+                assert!(rel_error(&a.div(c), Float::with_val(rp, &ra / &rc)) <= max_error, "{} / {}", a, c);
+                assert!(rel_error(&c.div(a), Float::with_val(rp, &rc / &ra)) <= max_error, "{} / {}", c, a);
+            }
+        }
+    }
+}
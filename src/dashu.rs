@@ -0,0 +1,65 @@
+//! Adapter for dashu-float.
+
+use std::ops::{Add, Div, Mul, Sub};
+use dashu_float::{ops::SquareRoot, round::mode::HalfEven, FBig};
+use dashu_int::{IBig, UBig};
+#[cfg(feature = "rug")]
+use rug::{Float, Integer, integer::Order};
+use crate::number::{BinaryOp, GlobalState, Number, Parts, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_DASHU_FLOAT");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, FBig<HalfEven, 2>>("dashu-float", VERSION)
+}
+
+impl Number<StubGlobalState> for FBig<HalfEven, 2> {
+    fn from_parts(parts: &Parts, gs: &StubGlobalState) -> Self {
+        let sign = if parts.negative { dashu_int::Sign::Negative } else { dashu_int::Sign::Positive };
+        let i = IBig::from_parts(sign, UBig::from_words(&parts.mantissa));
+        FBig::from_parts(i, parts.int_exp() as isize).with_precision(gs.precision()).value()
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        let repr = self.repr();
+        if repr.is_infinite() {
+            let inf = if repr.sign() == dashu_int::Sign::Positive { rug::float::Special::Infinity } else { rug::float::Special::NegInfinity };
+            return Float::with_val(p, inf);
+        }
+        let (sign, m) = repr.significand().clone().into_parts();
+        let mut ret = Float::with_val(p, Integer::from_digits(m.as_words(), Order::Lsf));
+        ret <<= repr.exponent();
+        if sign == dashu_int::Sign::Negative { -ret } else { ret }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        <&FBig<dashu_float::round::mode::HalfEven> as Add>::add(self, rhs)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        <&FBig<dashu_float::round::mode::HalfEven> as Sub>::sub(self, rhs)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        <&FBig<dashu_float::round::mode::HalfEven> as Mul>::mul(self, rhs)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        <&FBig<dashu_float::round::mode::HalfEven> as Div>::div(self, rhs)
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(<FBig<HalfEven, 2> as SquareRoot>::sqrt);
+
+    const LN: Option<UnaryOp<Self>> = Some(FBig::<HalfEven, 2>::ln);
+
+    const EXP: Option<UnaryOp<Self>> = Some(FBig::<HalfEven, 2>::exp);
+
+    const POW: Option<BinaryOp<Self>> = Some(FBig::<HalfEven, 2>::powf);
+}
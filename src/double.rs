@@ -0,0 +1,82 @@
+//! Adapter for hardware double precision floats used as the baseline.
+
+#[cfg(feature = "rug")]
+use rug::Float;
+use crate::number::{BinaryOp, Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Version of the compiler which provides the math functions.
+pub(crate) const VERSION: &str = env!("VERSION_RUSTC");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, f64>("f64", VERSION)
+}
+
+impl Number<StubGlobalState> for f64 {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        let m = parts.mantissa[parts.mantissa.len() - 1] as f64;
+        let ret = m * 2f64.powi(parts.exp - 64);
+        if parts.negative { -ret } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::Fixed(f64::MANTISSA_DIGITS as usize);
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        Float::with_val(p, *self)
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| f64::sqrt(*x));
+
+    const CBRT: Option<UnaryOp<Self>> = Some(|x| f64::cbrt(*x));
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| f64::ln(*x));
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| f64::exp(*x));
+
+    const POW: Option<BinaryOp<Self>> = Some(|x, n| f64::powf(*x, *n));
+
+    const SIN: Option<UnaryOp<Self>> = Some(|x| f64::sin(*x));
+
+    const ASIN: Option<UnaryOp<Self>> = Some(|x| f64::asin(*x));
+
+    const COS: Option<UnaryOp<Self>> = Some(|x| f64::cos(*x));
+
+    const ACOS: Option<UnaryOp<Self>> = Some(|x| f64::acos(*x));
+
+    const TAN: Option<UnaryOp<Self>> = Some(|x| f64::tan(*x));
+
+    const ATAN: Option<UnaryOp<Self>> = Some(|x| f64::atan(*x));
+
+    const SINH: Option<UnaryOp<Self>> = Some(|x| f64::sinh(*x));
+
+    const ASINH: Option<UnaryOp<Self>> = Some(|x| f64::asinh(*x));
+
+    const COSH: Option<UnaryOp<Self>> = Some(|x| f64::cosh(*x));
+
+    const ACOSH: Option<UnaryOp<Self>> = Some(|x| f64::acosh(*x));
+
+    const TANH: Option<UnaryOp<Self>> = Some(|x| f64::tanh(*x));
+
+    const ATANH: Option<UnaryOp<Self>> = Some(|x| f64::atanh(*x));
+}
@@ -0,0 +1,76 @@
+//! Adapter for double-double numbers of the qd crate.
+//!
+//! The crate provides arithmetic, square root, logarithm and exponent, other operations are not implemented.
+
+use std::fmt::Display;
+use qd::Quad;
+#[cfg(feature = "rug")]
+use rug::Float;
+use crate::number::{Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_QD");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, DoubleDouble>("double-double", VERSION)
+}
+
+/// Wrapper for qd::Quad, the unevaluated sum of two doubles.
+#[derive(Clone, Copy)]
+pub struct DoubleDouble(Quad);
+
+impl Display for DoubleDouble {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:e} + {:e}", self.0 .0, self.0 .1)
+    }
+}
+
+impl Number<StubGlobalState> for DoubleDouble {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        // the two most significant words are enough for 105 bits,
+        // each of them is split in halves which are exact in f64
+        let mut ret = Quad::ZERO;
+        let mut e = parts.exp;
+        for w in parts.mantissa.iter().rev().take(2) {
+            for half in [w >> 32, w & 0xffffffff] {
+                e -= 32;
+                ret += Quad::from_f64(half as f64 * 2f64.powi(e));
+            }
+        }
+        DoubleDouble(if parts.negative { -ret } else { ret })
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::Fixed(Quad::MANTISSA_DIGITS as usize);
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        Float::with_val(p, self.0 .0) + self.0 .1
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        DoubleDouble(self.0 + rhs.0)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        DoubleDouble(self.0 - rhs.0)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        DoubleDouble(self.0 * rhs.0)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        DoubleDouble(self.0 / rhs.0)
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| DoubleDouble(x.0.sqrt()));
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| DoubleDouble(x.0.ln()));
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| DoubleDouble(x.0.exp()));
+}
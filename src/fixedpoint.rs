@@ -0,0 +1,124 @@
+//! Adapter for 128-bit fixed-point numbers of the fixed crate.
+//!
+//! The crate provides arithmetic and square root, other operations are not implemented.
+//! Operands are mapped into the range the type can represent, see `Number::exp_range`.
+
+use fixed::FixedI128;
+use fixed::types::{I32F96, I64F64};
+use fixed::types::extra::LeEqU128;
+#[cfg(feature = "rug")]
+use rug::{Float, Integer};
+use crate::number::{Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_FIXED");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, I64F64>("fixed-i64f64", VERSION)
+}
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, I32F96>("fixed-i32f96", VERSION)
+}
+
+impl<Frac: LeEqU128> Number<StubGlobalState> for FixedI128<Frac> {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        // the 128 most significant bits of the mantissa are truncated to the fractional bits
+        let words = parts.mantissa.len();
+        let top = parts.mantissa[words - 1] as u128;
+        let next = if words > 1 { parts.mantissa[words - 2] as u128 } else { 0 };
+        let shift = 128 - Self::FRAC_NBITS as i32 - parts.exp;
+        let bits = if shift <= 0 {
+            i128::MAX
+        } else {
+            ((top << 64) | next).checked_shr(shift as u32).unwrap_or(0) as i128
+        };
+        let ret = Self::from_bits(bits);
+        if parts.negative { -ret } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::FixedPoint {
+        bits: (Self::INT_NBITS + Self::FRAC_NBITS) as usize,
+        frac: Self::FRAC_NBITS as usize,
+    };
+
+    fn exp_range() -> Option<(i32, i32)> {
+        Some((1 - Self::FRAC_NBITS as i32, Self::INT_NBITS as i32 - 1))
+    }
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        Float::with_val(p, Integer::from(self.to_bits())) >> Self::FRAC_NBITS
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        *self - *rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        *self / *rhs
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| FixedI128::sqrt(*x));
+}
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::extra::{U64, U96};
+    use crate::tasks::clip_exp_range;
+    use super::*;
+
+    /// Returns the smallest and the largest number with the exponent `exp` and both signs.
+    fn extremes<Frac: LeEqU128>(exp: i32) -> [FixedI128<Frac>; 4] {
+        let shift = exp - 1 + FixedI128::<Frac>::FRAC_NBITS as i32;
+        let lo = FixedI128::<Frac>::from_bits(1 << shift);
+        let hi = FixedI128::<Frac>::from_bits((1 << shift) - 1 + (1 << shift));
+        [lo, hi, -lo, -hi]
+    }
+
+    /// Checks that the task gives a representable result for all operands in the clipped range.
+    fn check_clipped_range<Frac: LeEqU128>(task: &str, op: fn(FixedI128<Frac>, FixedI128<Frac>) -> Option<FixedI128<Frac>>) {
+        let (min, max) = clip_exp_range(task, FixedI128::<Frac>::exp_range().unwrap());
+        assert!(min <= max, "empty range of {}", task);
+        for ea in min..=max {
+            for eb in min..=max {
+                for a in extremes::<Frac>(ea) {
+                    for b in extremes::<Frac>(eb) {
+                        let r = op(a, b);
+                        assert!(r.is_some(), "{} {} {} overflows", a, task, b);
+                        assert!(task == "sub" || task == "add" || r != Some(FixedI128::ZERO), "{} {} {} underflows", a, task, b);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_i32f96_operands_give_representable_results() {
+        check_clipped_range::<U96>("add", |a, b| a.checked_add(b));
+        check_clipped_range::<U96>("sub", |a, b| a.checked_sub(b));
+        check_clipped_range::<U96>("mul", |a, b| a.checked_mul(b));
+        check_clipped_range::<U96>("div", |a, b| a.checked_div(b));
+    }
+
+    #[test]
+    fn clipped_i64f64_operands_give_representable_results() {
+        check_clipped_range::<U64>("add", |a, b| a.checked_add(b));
+        check_clipped_range::<U64>("sub", |a, b| a.checked_sub(b));
+        check_clipped_range::<U64>("mul", |a, b| a.checked_mul(b));
+        check_clipped_range::<U64>("div", |a, b| a.checked_div(b));
+    }
+}
@@ -1,155 +1,576 @@
 use clap::{App, Arg};
-use dashu_float::{FBig, round::mode::HalfEven};
-use number::{Number, GlobalState};
+use number::{Number, GlobalState, Parts};
+use rand::{rngs::StdRng, SeedableRng};
+use report::{Format, Measurement, Outcome, RunInfo, TaskResult};
+use stats::Statistic;
+use std::convert::TryFrom;
+use std::hint::black_box;
 use std::time::{Duration, Instant};
 
-use crate::number::{StubGlobalState, AstroGlobalState};
-
 mod number;
 mod tasks;
+#[cfg(feature = "astro-float")]
 mod astro;
+#[cfg(feature = "rug")]
+mod mpfr;
+#[cfg(feature = "num-bigfloat")]
+mod numbigfloat;
+#[cfg(feature = "dashu-float")]
+mod dashu;
+mod double;
+#[cfg(feature = "qd")]
+mod doubledouble;
+mod quaddouble;
+#[cfg(feature = "bigdecimal")]
+mod bigdec;
+#[cfg(feature = "rust_decimal")]
+mod rustdecimal;
+#[cfg(feature = "fixed")]
+mod fixedpoint;
+mod verify;
+mod report;
+mod stats;
+mod worker;
+mod registry;
+
+/// Number of runs of each size in the scaling check.
+const SCALING_RUNS: usize = 3;
+
+/// Allowed deviation of the scaling check result from 1.
+const SCALING_TOLERANCE: f64 = 0.2;
+
+/// Rounds stop once the relative standard error of the mean round duration
+/// falls under this value.
+const RSE_THRESHOLD: f64 = 0.01;
+
+/// Options of the benchmark run.
+struct Options {
+    /// Minimal number of rounds of each task.
+    n: usize,
+    /// Time the measurement rounds of each task aim to take in total.
+    target_time: Duration,
+    /// Limit of the time spent on the measurement rounds of each task.
+    max_time: Duration,
+    /// If set, each lib/task runs in a child process which is aborted after this time.
+    timeout: Option<Duration>,
+    verify: bool,
+    /// Check that run time grows linearly with the number of operations.
+    self_check: bool,
+    /// Duration of untimed runs of each task before measurement.
+    warmup: Duration,
+    format: Format,
+    statistic: Statistic,
+}
 
 fn main() {
+    let backends = registry::backends();
+    let mut lib_names: Vec<&str> = backends.iter().map(|b| b.name).collect();
+    lib_names.push("all");
     let args = App::new("Float numbers benchmarks")
         .arg(
             Arg::with_name("lib")
                 .long("lib")
-                .possible_values(&["rug", "num-bigfloat", "dashu-float", "astro-float"])
+                .help("Library to benchmark, or all to benchmark all enabled libraries")
+                .possible_values(&lib_names)
                 .multiple(true)
                 .number_of_values(1)
-                .required(true)
+                .required_unless("list-libs")
                 .min_values(1),
         )
+        .arg(
+            Arg::with_name("list-libs")
+                .long("list-libs")
+                .help("List enabled libraries with their versions, precision and supported tasks"),
+        )
         .arg(
             Arg::with_name("task")
                 .long("task")
-                .possible_values(&["add", "sub", "mul", "div", "sqrt", "cbrt", "ln", "exp", "pow",
-                    "sin", "asin", "cos", "acos", "tan", "atan", "sinh", "asinh", "cosh", "acosh", "tanh", "atanh"])
+                .possible_values(tasks::TASKS)
                 .multiple(true)
                 .number_of_values(1)
-                .required(true)
+                .required_unless("list-libs")
                 .min_values(1),
         )
         .arg(
             Arg::with_name("n")
                 .short("n")
+                .help("Minimal number of measurement rounds of each task")
+                .takes_value(true)
+                .validator(validate_n)
+                .required_unless("list-libs"),
+        )
+        .arg(
+            Arg::with_name("precision")
+                .long("precision")
+                .help("Precision in bits")
+                .multiple(true)
+                .number_of_values(1)
+                .validator(validate_precision)
+                .default_value("132"),
+        )
+        .arg(
+            Arg::with_name("precision-sweep")
+                .long("precision-sweep")
+                .help("List of precisions in bits, e.g. 64,132,1000,10000, or a geometric range from:to:ratio, e.g. 64:16384:4")
+                .takes_value(true)
+                .validator(validate_precision_sweep)
+                .conflicts_with("precision"),
+        )
+        .arg(
+            Arg::with_name("verify")
+                .long("verify")
+                .help("Verify accuracy of results against rug computing with 64 extra bits of precision, requires the rug feature"),
+        )
+        .arg(
+            Arg::with_name("self-check")
+                .long("self-check")
+                .help("Check that run time of each task grows linearly with the number of operations"),
+        )
+        .arg(
+            Arg::with_name("warmup")
+                .long("warmup")
+                .help("Time in milliseconds each task runs untimed before measurement")
                 .takes_value(true)
-                .required(true),
+                .validator(validate_warmup)
+                .default_value("100"),
+        )
+        .arg(
+            Arg::with_name("target-time")
+                .long("target-time")
+                .help("Time the rounds of each task aim to take, e.g. 2s or 500ms")
+                .takes_value(true)
+                .validator(validate_duration)
+                .default_value("2s"),
+        )
+        .arg(
+            Arg::with_name("max-time")
+                .long("max-time")
+                .help("Limit of the time spent on the runs of each task, e.g. 30s; the cold run and one warm run are always done")
+                .takes_value(true)
+                .validator(validate_duration)
+                .default_value("30s"),
+        )
+        .arg(
+            Arg::with_name("timeout")
+                .long("timeout")
+                .help("Abort the measurement of a lib/task after the time, e.g. 60s")
+                .takes_value(true)
+                .validator(validate_duration),
+        )
+        .arg(
+            Arg::with_name("worker")
+                .long("worker")
+                .help("Run a single lib/task with operands read from stdin, used with --timeout")
+                .hidden(true),
+        )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .help("Seed of the random number generator used to generate operands")
+                .takes_value(true)
+                .validator(validate_seed),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .help("Output format")
+                .possible_values(&["text", "json", "csv", "markdown"])
+                .default_value("text"),
+        )
+        .arg(
+            Arg::with_name("statistic")
+                .long("statistic")
+                .help("Statistic of the round durations reported as the result")
+                .possible_values(&["min", "median", "mean"])
+                .default_value("min"),
         )
         .get_matches();
 
-    let libs: Vec<String> = args
-        .values_of("lib")
-        .unwrap()
-        .map(|s| s.to_string())
-        .collect();
+    if args.is_present("list-libs") {
+        list_libs(&backends);
+        return;
+    }
+    if args.is_present("verify") && !cfg!(feature = "rug") {
+        clap::Error::with_description("--verify requires the rug feature", clap::ErrorKind::ArgumentConflict).exit();
+    }
+
+    let libs: Vec<String> = if args.values_of("lib").unwrap().any(|s| s == "all") {
+        backends.iter().map(|b| b.name.to_string()).collect()
+    } else {
+        args.values_of("lib").unwrap().map(|s| s.to_string()).collect()
+    };
     let tasks: Vec<String> = args
         .values_of("task")
         .unwrap()
         .map(|s| s.to_string())
         .collect();
-    let n: usize = args.value_of("n").unwrap().parse().expect("invalid n");
-
-    print!("{: >15}", " ");
-    for lib in &libs {
-        print!("{: >15}", lib);
-    }
-    println!();
-
-    for task in &tasks {
-        print!("{: >15}", task);
-        for lib in &libs {
-            let res = match lib.as_str() {
-                "rug" => benchmark_lib_task::<StubGlobalState, rug::Float>(task, n),
-                "num-bigfloat" => benchmark_lib_task::<StubGlobalState, num_bigfloat::BigFloat>(task, n),
-                "dashu-float" => benchmark_lib_task::<StubGlobalState, FBig<HalfEven, 2>>(task, n),
-                "astro-float" => benchmark_lib_task::<AstroGlobalState, crate::astro::AstroFloat>(task, n),
-                _ => unreachable!(),
+    let opts = Options {
+        n: args.value_of("n").unwrap().parse().expect("invalid n"),
+        target_time: parse_duration(args.value_of("target-time").unwrap()).expect("target time is validated"),
+        max_time: parse_duration(args.value_of("max-time").unwrap()).expect("max time is validated"),
+        timeout: args.value_of("timeout").map(|v| parse_duration(v).expect("timeout is validated")),
+        verify: args.is_present("verify"),
+        self_check: args.is_present("self-check"),
+        warmup: Duration::from_millis(args.value_of("warmup").unwrap().parse().expect("invalid warmup")),
+        format: Format::from_name(args.value_of("format").unwrap()),
+        statistic: Statistic::from_name(args.value_of("statistic").unwrap()),
+    };
+
+    let seed: u64 = match args.value_of("seed") {
+        Some(s) => s.parse().expect("invalid seed"),
+        None => rand::random(),
+    };
+    let precisions: Vec<usize> = match args.value_of("precision-sweep") {
+        Some(sweep) => parse_precision_sweep(sweep).expect("precision sweep is validated"),
+        None => args
+            .values_of("precision")
+            .unwrap()
+            .map(|s| s.parse().expect("invalid precision"))
+            .collect(),
+    };
+
+    if args.is_present("worker") {
+        let corpus = worker::read_corpus();
+        let outcome = (registry::find(&libs[0]).run)(&tasks[0], &corpus, precisions[0], &opts);
+        worker::write_outcome(&outcome);
+        return;
+    }
+
+    if opts.format == Format::Text {
+        println!("Seed: {}", seed);
+        println!();
+    }
+
+    let mut results = vec![];
+    for &p in &precisions {
+        if opts.format == Format::Text {
+            println!("Results for the precision {} bits, time per operation:", p);
+        }
+        results.extend(benchmark_table(&libs, &tasks, p, &opts, seed));
+        if opts.format == Format::Text {
+            println!();
+        }
+    }
+
+    let info = RunInfo {
+        libs: libs.iter().map(|lib| registry::find(lib).info()).collect(),
+        precisions: &precisions,
+        n: opts.n,
+        seed,
+    };
+    match opts.format {
+        Format::Text => {}
+        Format::Json => report::print_json(&info, &results),
+        Format::Csv => report::print_csv(&info, &results),
+        Format::Markdown => report::print_markdown(&info, &results),
+    }
+}
+
+/// Prints the enabled libraries with their versions, precision and supported tasks.
+fn list_libs(backends: &[&registry::Backend]) {
+    for b in backends {
+        let tasks: Vec<&str> = tasks::TASKS.iter().cloned().filter(|t| (b.supports)(t)).collect();
+        println!("{} {}, {}: {}", b.name, b.version, b.precision, tasks.join(", "));
+    }
+}
+
+/// Accepts a number of rounds which is positive and fits in u32.
+fn validate_n(v: String) -> Result<(), String> {
+    match v.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("n must be an integer from 1 to {}", u32::MAX)),
+    }
+}
+
+/// Accepts a precision in bits which is at least 1.
+fn validate_precision(v: String) -> Result<(), String> {
+    match v.trim().parse::<usize>() {
+        Ok(p) if p > 0 => Ok(()),
+        _ => Err(format!("precision must be a positive integer, got {}", v)),
+    }
+}
+
+/// Accepts a precision sweep which gives at least one valid precision.
+fn validate_precision_sweep(v: String) -> Result<(), String> {
+    parse_precision_sweep(&v).map(|_| ())
+}
+
+/// Accepts a duration in the format of `parse_duration`.
+fn validate_duration(v: String) -> Result<(), String> {
+    parse_duration(&v).map(|_| ())
+}
+
+/// Accepts a warm-up time in whole milliseconds.
+fn validate_warmup(v: String) -> Result<(), String> {
+    v.parse::<u64>().map(|_| ()).map_err(|_| format!("warmup must be a whole number of milliseconds, got {}", v))
+}
+
+/// Accepts a seed which fits in u64.
+fn validate_seed(v: String) -> Result<(), String> {
+    v.parse::<u64>().map(|_| ()).map_err(|_| format!("seed must be an integer from 0 to {}, got {}", u64::MAX, v))
+}
+
+/// Parses a duration with the unit suffix `s` or `ms`, e.g. `2s` or `500ms`.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (v, unit) = match s.strip_suffix("ms") {
+        Some(v) => (v, 0.001),
+        None => (s.strip_suffix('s').unwrap_or(s), 1.0),
+    };
+    v.trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|v| Duration::try_from_secs_f64(v * unit).ok())
+        .ok_or_else(|| format!("duration must be a non-negative number with the unit s or ms, e.g. 2s or 500ms, got {}", s))
+}
+
+/// Parses either a comma-separated list of precisions,
+/// or a geometric range `from:to:ratio` which includes `from` and all
+/// following terms not exceeding `to`.
+fn parse_precision_sweep(s: &str) -> Result<Vec<usize>, String> {
+    let parts = s
+        .split([',', ':'])
+        .map(|v| validate_precision(v.to_string()).map(|_| v.trim().parse().unwrap()))
+        .collect::<Result<Vec<usize>, String>>()?;
+
+    if s.contains(':') {
+        if s.contains(',') || parts.len() != 3 {
+            return Err("precision range must be specified as from:to:ratio".to_string());
+        }
+        let (from, to, ratio) = (parts[0], parts[1], parts[2]);
+        if ratio < 2 {
+            return Err("ratio of the precision range must be at least 2".to_string());
+        }
+        if from > to {
+            return Err(format!("precision range {} is empty", s));
+        }
+        let mut ret = vec![];
+        let mut p = Some(from);
+        while let Some(v) = p.filter(|&v| v <= to) {
+            ret.push(v);
+            p = v.checked_mul(ratio);
+        }
+        Ok(ret)
+    } else {
+        Ok(parts)
+    }
+}
+
+/// Runs all tasks for all libraries with precision `p`.
+/// In the text format results are printed as a table while they are collected.
+fn benchmark_table(libs: &[String], tasks: &[String], p: usize, opts: &Options, seed: u64) -> Vec<TaskResult> {
+    let text = opts.format == Format::Text;
+    let w = if opts.verify { 60 } else { 35 } + if opts.self_check { 15 } else { 0 };
+
+    if text {
+        print!("{: >15}", " ");
+        for lib in libs {
+            let info = registry::find(lib).info();
+            print!("{: >w$}", format!("{} {}", lib, info.version_note(p)), w = w);
+        }
+        println!();
+    }
+
+    let mut results = vec![];
+    for task in tasks {
+        if text {
+            print!("{: >15}", task);
+        }
+        let corpus = get_range_for_task(task, p, &mut task_rng(seed, task, p));
+        for lib in libs {
+            let backend = registry::find(lib);
+            let outcome = if !backend.precision.supports(p) || !(backend.supports)(task) {
+                // known without running a child process
+                Outcome::NotApplicable
+            } else {
+                match opts.timeout {
+                    Some(timeout) => worker::run_in_child(lib, task, &corpus, p, opts, timeout),
+                    None => (backend.run)(task, &corpus, p, opts),
+                }
+            };
+            let res = TaskResult {
+                lib: lib.clone(),
+                version: backend.version,
+                task: task.clone(),
+                precision: p,
+                outcome,
             };
-            print!("{: >15}", res);
+            if text {
+                print!("{: >w$}", res.text(), w = w);
+            }
+            if let Outcome::Measured(Measurement { scaling: Some(scaling), .. }) = res.outcome {
+                if (scaling - 1.0).abs() > SCALING_TOLERANCE {
+                    eprintln!("warning: {} {} at {} bits: run time scales by {:.2} of the expected, \
+                        operations may have been optimized out", lib, task, p, scaling);
+                }
+            }
+            results.push(res);
+        }
+        if text {
+            println!();
         }
+    }
+
+    if text {
         println!();
+        println!("Statistics of the rounds, time per operation:");
+        for res in &results {
+            if let Some(stats) = res.statistics() {
+                println!("{: >15} {: <20} {}", res.task, res.lib, stats);
+            }
+        }
     }
+    results
 }
 
-fn benchmark_lib_task<G: GlobalState, T: Number<G>>(task: &str, n: usize) -> String {
-    let vals: Vec<T> = get_range_for_task(task);
-    let mut durations: Vec<u32> = Vec::new();
-    for _ in 0..n {
-        let mut full_dur = 0;
-        let mut iter = 1;
-        let mut niter = 0;
-        while full_dur < 1000 && iter < 16 {
-            niter += iter;
-            for _ in 0..iter {
-                let (_a, d) = run_task_using::<G, T>(task, &vals);
-                full_dur += d.as_micros();
-            }
-            iter*=2;
+fn benchmark_lib_task<G: GlobalState, T: Number<G>>(task: &str, corpus: &[Parts], p: usize, opts: &Options) -> Outcome {
+    benchmark_lib_task_in::<G, T>(task, corpus, T::global_state(p), opts)
+}
+
+/// Runs the task for numbers created with the global state `gs`.
+fn benchmark_lib_task_in<G: GlobalState, T: Number<G>>(task: &str, corpus: &[Parts], gs: G, opts: &Options) -> Outcome {
+    let p = gs.precision();
+    if !T::PRECISION.supports(p) || !T::supports(task) {
+        return Outcome::NotApplicable;
+    }
+    let clipped;
+    let corpus = match T::exp_range() {
+        Some(range) => {
+            clipped = clip_corpus(task, corpus, p, range);
+            &clipped
         }
-        durations.push((full_dur/niter/1000) as u32);
-    }
-    durations.sort_unstable();
-    format!("{} ms", durations[0])
-}
-
-fn get_range_for_task<G: GlobalState, T: Number<G>>(task: &str) -> Vec<T> {
-    let gs = T::global_state();
-    // exponent has base 10
-    let (n, exp_from, exp_to, sign_positive) = match task {
-        "add" => (1000000, -10, 10, false),
-        "sub" => (1000000, -10, 10, false),
-        "mul" => (1000000, -10, 10, false),
-        "div" => (1000000, -10, 10, false),
-        "sqrt" => (100000, -10, 10, true),
-        "cbrt" => (100000, -10, 10, false),
-        "ln" => (10000, -10, 10, true),
-        "exp" => (10000, -10, 3, false),
-        "pow" => (10000, -5, 5, false),
-        "sin" => (10000, -10, 3, false),
-        "cos" => (10000, -10, 3, false),
-        "tan" => (10000, -10, 3, false),
-        "sinh" => (10000, -10, 3, false),
-        "cosh" => (10000, -10, 3, false),
-        "tanh" => (10000, -10, 3, false),
-        "asin" => (10000, -10, 0, false),
-        "acos" => (10000, -10, 0, false),
-        "atan" => (10000, -10, 0, false),
-        "asinh" => (10000, -10, 10, false),
-        "acosh" => (10000, 1, 10, true),
-        "atanh" => (10000, -10, 0, false),
-        _ => unreachable!(),
+        None => corpus,
     };
-    T::rand_normal(n, exp_from, exp_to, gs, sign_positive)
+    let vals: Vec<T> = corpus.iter().map(|v| T::from_parts(v, &gs)).collect();
+
+    // the cold and the pilot runs are always done, other runs stop at `max_time`
+    let (_a, cold) = run_task_using::<G, T>(task, &vals);
+    let mut total = cold;
+    let warmup_start = Instant::now();
+    while warmup_start.elapsed() < opts.warmup && total + warmup_start.elapsed() < opts.max_time {
+        run_task_using::<G, T>(task, &vals);
+    }
+    total += warmup_start.elapsed();
+
+    // the pilot run determines the number of task runs in a round,
+    // so that `n` rounds take about `target_time`
+    let (_a, pilot) = run_task_using::<G, T>(task, &vals);
+    total += pilot;
+    let round_time = opts.target_time / u32::try_from(opts.n).expect("n is validated to fit in u32");
+    let pilot_secs = pilot.as_secs_f64().max(1e-9);
+    let runs = (round_time.as_secs_f64() / pilot_secs).max(1.0) as u32;
+
+    let mut durations: Vec<Duration> = Vec::new();
+    while total < opts.max_time && (durations.len() < opts.n || stats::relative_std_error(&durations) > RSE_THRESHOLD) {
+        // the last round is shortened to the remaining time
+        let runs = runs.min(((opts.max_time - total).as_secs_f64() / pilot_secs).ceil() as u32).max(1);
+        let mut full_dur = Duration::ZERO;
+        for _ in 0..runs {
+            let (_a, d) = run_task_using::<G, T>(task, &vals);
+            full_dur += d;
+        }
+        total += full_dur;
+        durations.push(full_dur / runs);
+    }
+    if durations.is_empty() {
+        // the time ran out before the rounds, the warm pilot run is the only round
+        durations.push(pilot);
+    }
+    #[cfg(feature = "rug")]
+    let accuracy = if opts.verify {
+        let vp = T::PRECISION.fixed().map_or(p, |fp| fp.min(p));
+        Some(verify::verify_task::<G, T>(task, &vals, vp))
+    } else {
+        None
+    };
+    #[cfg(not(feature = "rug"))]
+    let accuracy = None;
+    let scaling = if opts.self_check { Some(check_scaling::<G, T>(task, &vals)) } else { None };
+    Outcome::Measured(Measurement::new(durations, tasks::op_count(task, vals.len()), cold, accuracy, scaling, opts.statistic))
+}
+
+/// Returns the ratio of the measured growth of run time to the expected one
+/// when the task runs on all operands instead of a half of them.
+/// A value far from 1 means the operations are not performed as expected,
+/// e.g. the optimizer eliminated a part of them.
+fn check_scaling<G: GlobalState, T: Number<G>>(task: &str, vals: &[T]) -> f64 {
+    let half = &vals[..vals.len() / 2];
+    let min_time = |vals: &[T]| (0..SCALING_RUNS).map(|_| run_task_using::<G, T>(task, vals).1).min().unwrap();
+    let expected = tasks::op_count(task, vals.len()) as f64 / tasks::op_count(task, half.len()) as f64;
+    min_time(vals).as_secs_f64() / min_time(half).as_secs_f64() / expected
+}
+
+/// Returns the generator of the operands of `task` at precision `p`,
+/// so that the operands depend only on the seed and not on the other tasks and precisions of the run.
+fn task_rng(seed: u64, task: &str, p: usize) -> StdRng {
+    // FNV-1a keeps the derived seeds stable across Rust versions
+    let mut h: u64 = 0xcbf29ce484222325;
+    for b in seed.to_le_bytes().iter().chain(&(p as u64).to_le_bytes()).chain(task.as_bytes()) {
+        h = (h ^ *b as u64).wrapping_mul(0x100000001b3);
+    }
+    StdRng::seed_from_u64(h)
+}
+
+/// Returns operands for the task which are shared by all libraries.
+fn get_range_for_task(task: &str, p: usize, rng: &mut StdRng) -> Vec<Parts> {
+    let (n, exp_from, exp_to, sign_positive) = tasks::task_range(task, p);
+    let mut corpus: Vec<Parts> = (0..n).map(|_| Parts::random_normal(rng, p, exp_from, exp_to, sign_positive)).collect();
+    if task == "pow" {
+        // the first half of operands are the bases which must be positive for the result to be real
+        corpus[..n / 2].iter_mut().for_each(|v| v.negative = false);
+    }
+    corpus
+}
+
+/// Maps the exponents of the operands linearly from the range of the task
+/// to the part of it which a library with the exponent range `exp_range` can compute in.
+fn clip_corpus(task: &str, corpus: &[Parts], p: usize, exp_range: (i32, i32)) -> Vec<Parts> {
+    let (_, exp_from, exp_to, _) = tasks::task_range(task, p);
+    let (from, to) = (number::binary_exp(exp_from), number::binary_exp(exp_to));
+    let (min, max) = tasks::clip_exp_range(task, exp_range);
+    let (min, max) = (min.max(from), max.min(to));
+    corpus
+        .iter()
+        .map(|v| Parts {
+            mantissa: v.mantissa.clone(),
+            exp: min + ((v.exp - from) as i64 * (max - min) as i64 / (to - from).max(1) as i64) as i32,
+            negative: v.negative,
+        })
+        .collect()
+}
+
+/// Returns the operation of a task which was checked to be supported.
+/// Operations are called through closures, so that the constant function pointer is inlined.
+fn supported<F>(op: Option<F>) -> F {
+    op.expect("unsupported task")
 }
 
 fn run_task_using<G: GlobalState, T: Number<G>>(task: &str, vals: &[T]) -> (T, Duration) {
     let start_time = Instant::now();
 
-    let a = match task {
+    let a = black_box(match task {
         "add" => tasks::task_for_two_args::<G, T>(vals, T::add),
         "sub" => tasks::task_for_two_args::<G, T>(vals, T::sub),
         "mul" => tasks::task_for_two_args::<G, T>(vals, T::mul),
         "div" => tasks::task_for_two_args::<G, T>(vals, T::div),
-        "sqrt" => tasks::task_for_one_arg::<G, T>(vals, T::sqrt),
-        "cbrt" => tasks::task_for_one_arg::<G, T>(vals, T::cbrt),
-        "ln" => tasks::task_for_one_arg::<G, T>(vals, T::ln),
-        "exp" => tasks::task_for_one_arg::<G, T>(vals, T::exp),
-        "pow" => tasks::task_for_two_args::<G, T>(vals, T::pow),
-        "sin" => tasks::task_for_one_arg::<G, T>(vals, T::sin),
-        "cos" => tasks::task_for_one_arg::<G, T>(vals, T::cos),
-        "tan" => tasks::task_for_one_arg::<G, T>(vals, T::tan),
-        "sinh" => tasks::task_for_one_arg::<G, T>(vals, T::sinh),
-        "cosh" => tasks::task_for_one_arg::<G, T>(vals, T::cosh),
-        "tanh" => tasks::task_for_one_arg::<G, T>(vals, T::tanh),
-        "asin" => tasks::task_for_one_arg::<G, T>(vals, T::asin),
-        "acos" => tasks::task_for_one_arg::<G, T>(vals, T::acos),
-        "atan" => tasks::task_for_one_arg::<G, T>(vals, T::atan),
-        "asinh" => tasks::task_for_one_arg::<G, T>(vals, T::asinh),
-        "acosh" => tasks::task_for_one_arg::<G, T>(vals, T::acosh),
-        "atanh" => tasks::task_for_one_arg::<G, T>(vals, T::atanh),
+        "sqrt" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::SQRT)(v)),
+        "cbrt" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::CBRT)(v)),
+        "ln" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::LN)(v)),
+        "exp" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::EXP)(v)),
+        "pow" => tasks::task_for_two_args::<G, T>(vals, |u, v| supported(T::POW)(u, v)),
+        "sin" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::SIN)(v)),
+        "cos" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::COS)(v)),
+        "tan" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::TAN)(v)),
+        "sinh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::SINH)(v)),
+        "cosh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::COSH)(v)),
+        "tanh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::TANH)(v)),
+        "asin" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ASIN)(v)),
+        "acos" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ACOS)(v)),
+        "atan" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ATAN)(v)),
+        "asinh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ASINH)(v)),
+        "acosh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ACOSH)(v)),
+        "atanh" => tasks::task_for_one_arg::<G, T>(vals, |v| supported(T::ATANH)(v)),
         _ => unreachable!(),
-    };
+    });
 
     let time = start_time.elapsed();
     (a, time)
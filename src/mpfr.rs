@@ -0,0 +1,79 @@
+//! Adapter for rug, which is based on MPFR.
+
+use std::ops::{Add, Div, Mul, Sub};
+use rug::{Float, Integer, integer::Order, ops::CompleteRound};
+use crate::number::{BinaryOp, GlobalState, Number, Parts, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_RUG");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, Float>("rug", VERSION)
+}
+
+impl Number<StubGlobalState> for rug::Float {
+    fn from_parts(parts: &Parts, gs: &StubGlobalState) -> Self {
+        let mut ret = Float::with_val(gs.precision() as u32, Integer::from_digits(&parts.mantissa, Order::Lsf));
+        ret <<= parts.int_exp();
+        if parts.negative { -ret } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    fn to_reference(&self, p: u32) -> Float {
+        Float::with_val(p, self)
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        <&Self as Add<&Self>>::add(self, rhs).complete(self.prec())
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        <&Self as Sub<&Self>>::sub(self, rhs).complete(self.prec())
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        <&Self as Mul<&Self>>::mul(self, rhs).complete(self.prec())
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        <&Self as Div<&Self>>::div(self, rhs).complete(self.prec())
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| x.clone().sqrt());
+
+    const CBRT: Option<UnaryOp<Self>> = Some(|x| x.clone().cbrt());
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| x.clone().ln());
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| x.clone().exp());
+
+    const POW: Option<BinaryOp<Self>> = Some(|x, n| rug::ops::Pow::pow(x.clone(), n));
+
+    const SIN: Option<UnaryOp<Self>> = Some(|x| x.clone().sin());
+
+    const ASIN: Option<UnaryOp<Self>> = Some(|x| x.clone().asin());
+
+    const COS: Option<UnaryOp<Self>> = Some(|x| x.clone().cos());
+
+    const ACOS: Option<UnaryOp<Self>> = Some(|x| x.clone().acos());
+
+    const TAN: Option<UnaryOp<Self>> = Some(|x| x.clone().tan());
+
+    const ATAN: Option<UnaryOp<Self>> = Some(|x| x.clone().atan());
+
+    const SINH: Option<UnaryOp<Self>> = Some(|x| x.clone().sinh());
+
+    const ASINH: Option<UnaryOp<Self>> = Some(|x| x.clone().asinh());
+
+    const COSH: Option<UnaryOp<Self>> = Some(|x| x.clone().cosh());
+
+    const ACOSH: Option<UnaryOp<Self>> = Some(|x| x.clone().acosh());
+
+    const TANH: Option<UnaryOp<Self>> = Some(|x| x.clone().tanh());
+
+    const ATANH: Option<UnaryOp<Self>> = Some(|x| x.clone().atanh());
+}
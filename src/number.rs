@@ -1,513 +1,202 @@
-use std::{
-    fmt::Display,
-    ops::{Add, Div, Mul, Sub, Deref, DerefMut}, cell::RefCell,
-    rc::Rc,
-};
-use astro_float::Consts;
-use dashu_float::{round::mode::HalfEven, FBig};
-use dashu_int::{IBig, UBig};
-use rug::{rand::RandState, Float, ops::CompleteRound};
-use rand::random;
-use crate::astro::AstroFloat;
-
-pub(crate) trait GlobalState {}
-
-pub struct StubGlobalState {}
-
-impl GlobalState for StubGlobalState {}
-
-pub struct AstroGlobalState {
-    cc: Rc<RefCell<Consts>>
+use std::fmt::Display;
+#[cfg(feature = "rug")]
+use rug::Float;
+use rand::Rng;
+
+/// Library-independent binary representation of an operand:
+/// (-1)^negative * 0.mantissa * 2^exp.
+/// Mantissa words are stored least significant first,
+/// and the most significant bit of the mantissa is set.
+pub(crate) struct Parts {
+    pub mantissa: Vec<u64>,
+    pub exp: i32,
+    pub negative: bool,
 }
 
-impl GlobalState for AstroGlobalState {}
-
-pub(crate) trait Number<G: GlobalState>
-where
-    Self: Sized,
-    Self: Display,
-    Self: Clone,
-{
-    fn rand_normal(n: usize, exp_from: i32, exp_to: i32, gs: G, sign_positive: bool) -> Vec<Self>;
-
-    fn global_state() -> G;
-
-    fn add(&self, rhs: &Self) -> Self;
-
-    fn sub(&self, rhs: &Self) -> Self;
-
-    fn mul(&self, rhs: &Self) -> Self;
-
-    fn div(&self, rhs: &Self) -> Self;
-
-    fn sqrt(&self) -> Self;
-
-    fn cbrt(&self) -> Self;
-
-    fn ln(&self) -> Self;
-
-    fn exp(&self) -> Self;
-
-    fn pow(&self, n: &Self) -> Self;
-    
-    fn sin(&self) -> Self;
-        
-    fn asin(&self) -> Self;
-
-    fn cos(&self) -> Self;
-        
-    fn acos(&self) -> Self;
-
-    fn tan(&self) -> Self;
-        
-    fn atan(&self) -> Self;
-
-    fn sinh(&self) -> Self;
-        
-    fn asinh(&self) -> Self;
-
-    fn cosh(&self) -> Self;
-        
-    fn acosh(&self) -> Self;
-
-    fn tanh(&self) -> Self;
-        
-    fn atanh(&self) -> Self;
-}
-
-impl Number<StubGlobalState> for rug::Float {
-    fn rand_normal(n: usize, exp_from: i32, exp_to: i32, _gs: StubGlobalState, sign_positive: bool) -> Vec<Self> {
-        let mut rand = RandState::new();
-        let mut ret = vec![];
-        for _ in 0..n {
-            let sign = if sign_positive || random::<i8>() & 1 == 0 {1} else {-1};
-            let exp_range = exp_to - exp_from;
-            let exp = (if exp_range != 0 {random::<i32>().abs() % exp_range} else {0}) + exp_from;
-            let f = Float::with_val(132,Float::random_bits(&mut rand));
-            let p = Float::with_val(132, Float::i_pow_u(10, exp.unsigned_abs()));
-            ret.push(Float::with_val(132, if exp >= 0 { f*p*sign } else { f/p*sign }));
+impl Parts {
+    /// Returns a random normal number with `p` bits of mantissa.
+    /// The exponent range `exp_from`..`exp_to` has base 10.
+    pub fn random_normal<R: Rng>(rng: &mut R, p: usize, exp_from: i32, exp_to: i32, sign_positive: bool) -> Self {
+        let words = p.div_ceil(64);
+        let mut mantissa = vec![0u64; words];
+        mantissa.iter_mut().for_each(|v| *v = rng.gen());
+
+        // keep exactly p significant bits
+        mantissa[0] &= u64::MAX << (words * 64 - p);
+        mantissa[words - 1] |= 1 << 63;
+
+        let exp_from = binary_exp(exp_from);
+        let exp_to = binary_exp(exp_to);
+        let exp = if exp_to > exp_from { rng.gen_range(exp_from..exp_to) } else { exp_from };
+
+        Parts {
+            mantissa,
+            exp,
+            negative: !sign_positive && rng.gen::<i8>() & 1 != 0,
         }
-        ret
     }
 
-    fn global_state() -> StubGlobalState {
-        StubGlobalState {}
-    }
-
-    fn add(&self, rhs: &Self) -> Self {
-        <&Self as Add<&Self>>::add(self, rhs).complete(self.prec())
-    }
-
-    fn sub(&self, rhs: &Self) -> Self {
-        <&Self as Sub<&Self>>::sub(self, rhs).complete(self.prec())
-    }
-
-    fn mul(&self, rhs: &Self) -> Self {
-        <&Self as Mul<&Self>>::mul(self, rhs).complete(self.prec())
-    }
-
-    fn div(&self, rhs: &Self) -> Self {
-        <&Self as Div<&Self>>::div(self, rhs).complete(self.prec())
-    }
-
-    fn sqrt(&self) -> Self {
-        self.clone().sqrt()
-    }
-
-    fn cbrt(&self) -> Self {
-        self.clone().cbrt()
-    }
-
-    fn ln(&self) -> Self {
-        self.clone().ln()
-    }
-
-    fn exp(&self) -> Self {
-        self.clone().exp()
-    }
-
-    fn pow(&self, n: &Self) -> Self {
-        rug::ops::Pow::pow(self.clone(), n)
-    }
-    
-    fn sin(&self) -> Self {
-        self.clone().sin()
-    }
-        
-    fn asin(&self) -> Self {
-        self.clone().asin()
-    }
-
-    fn cos(&self) -> Self {
-        self.clone().cos()
-    }
-        
-    fn acos(&self) -> Self {
-        self.clone().acos()
-    }
-
-    fn tan(&self) -> Self {
-        self.clone().tan()
-    }
-        
-    fn atan(&self) -> Self {
-        self.clone().atan()
-    }
-
-    fn sinh(&self) -> Self {
-        self.clone().sinh()
-    }
-        
-    fn asinh(&self) -> Self{
-        self.clone().asinh()
-    }
-
-    fn cosh(&self) -> Self{
-        self.clone().cosh()
-    }
-        
-    fn acosh(&self) -> Self{
-        self.clone().acosh()
-    }
-
-    fn tanh(&self) -> Self{
-        self.clone().tanh()
-    }
-        
-    fn atanh(&self) -> Self {
-        self.clone().atanh()
+    /// Exponent of the mantissa interpreted as an integer.
+    #[cfg(any(feature = "rug", feature = "num-bigfloat", feature = "dashu-float", feature = "bigdecimal"))]
+    pub fn int_exp(&self) -> i32 {
+        self.exp - self.mantissa.len() as i32 * 64
     }
 }
 
-impl Number<StubGlobalState> for num_bigfloat::BigFloat {
-    fn rand_normal(n: usize, exp_from: i32, exp_to: i32, _gs: StubGlobalState, sign_positive: bool) -> Vec<Self> {
-        let mut ret = vec![];
-        for _ in 0..n {
-            let mut mantissa = [0i16; 10];
-            mantissa.iter_mut().for_each(|v| *v = (random::<u16>() % 10000) as i16);
-
-            if mantissa[9] == 0 {
-                mantissa[9] = 9999;
-            }
-            while mantissa[9] / 1000 == 0 {
-                mantissa[9] *= 10;
-            }
-            let sign = if sign_positive || random::<i8>() & 1 == 0 {1} else {-1};
-            let exp_range = exp_to - exp_from;
-            let exp = (if exp_range != 0 {random::<i32>().abs() % exp_range} else {0}) + exp_from - 40;
-            ret.push(num_bigfloat::BigFloat::from_raw_parts(mantissa, 40, sign, exp as i8));
-        }
-        ret
-    }
-
-    fn global_state() -> StubGlobalState {
-        StubGlobalState {}
-    }
-
-    fn add(&self, rhs: &Self) -> Self {
-        num_bigfloat::BigFloat::add(self, rhs)
-    }
-
-    fn sub(&self, rhs: &Self) -> Self {
-        num_bigfloat::BigFloat::sub(self, rhs)
-    }
-
-    fn mul(&self, rhs: &Self) -> Self {
-        num_bigfloat::BigFloat::mul(self, rhs)
-    }
-
-    fn div(&self, rhs: &Self) -> Self {
-        num_bigfloat::BigFloat::div(self, rhs)
-    }
-
-    fn sqrt(&self) -> Self {
-        self.sqrt()
-    }
-
-    fn cbrt(&self) -> Self {
-        self.cbrt()
-    }
-
-    fn ln(&self) -> Self {
-        self.ln()
-    }
-
-    fn exp(&self) -> Self {
-        self.exp()
-    }
-
-    fn pow(&self, n: &Self) -> Self {
-        num_bigfloat::BigFloat::pow(self, n)
-    }
-    
-    fn sin(&self) -> Self {
-        self.sin()
-    }
-        
-    fn asin(&self) -> Self {
-        self.asin()
-    }
-
-    fn cos(&self) -> Self {
-        self.cos()
-    }
-        
-    fn acos(&self) -> Self {
-        self.acos()
-    }
-
-    fn tan(&self) -> Self {
-        self.tan()
-    }
-        
-    fn atan(&self) -> Self {
-        self.atan()
-    }
-
-    fn sinh(&self) -> Self {
-        self.sinh()
-    }
-
-    fn asinh(&self) -> Self {
-        self.asinh()
-    }
+/// Converts an exponent with base 10 to an exponent with base 2.
+pub(crate) fn binary_exp(exp: i32) -> i32 {
+    (exp as i64 * 3321928095 / 1000000000) as i32
+}
 
-    fn cosh(&self) -> Self {
-        self.cosh()
-    }
+pub(crate) trait GlobalState {
+    /// Precision in bits of the numbers created using this state.
+    fn precision(&self) -> usize;
+}
 
-    fn acosh(&self) -> Self {
-        self.acosh()
-    }
+/// Global state of libraries which need only the precision.
+pub struct StubGlobalState {
+    p: usize,
+}
 
-    fn tanh(&self) -> Self {
-        self.tanh()
+impl StubGlobalState {
+    pub fn new(p: usize) -> Self {
+        StubGlobalState { p }
     }
+}
 
-    fn atanh(&self) -> Self {
-        self.atanh()
+impl GlobalState for StubGlobalState {
+    fn precision(&self) -> usize {
+        self.p
     }
 }
 
+/// How the precision a library computes with relates to the requested one.
+#[derive(Clone, Copy)]
+#[cfg_attr(not(all(feature = "num-bigfloat", feature = "fixed")), allow(dead_code))]
+pub(crate) enum PrecisionModel {
+    /// Computes with the requested precision.
+    Requested,
+    /// Computes only with this precision in bits.
+    Only(usize),
+    /// Always computes with this precision in bits.
+    Fixed(usize),
+    /// Fixed-point numbers of `bits` bits including the sign bit, `frac` of them fractional.
+    /// Small numbers have fewer significant bits than large ones.
+    FixedPoint { bits: usize, frac: usize },
+}
 
-
-impl Number<AstroGlobalState> for AstroFloat {
-
-    fn rand_normal(n: usize, exp_from: i32, exp_to: i32, gs: AstroGlobalState, sign_positive: bool) -> Vec<Self> {
-        let mut ret = vec![];
-        for _ in 0..n {
-            ret.push(AstroFloat::random_normal(exp_from, exp_to, gs.cc.clone(), sign_positive));
+impl PrecisionModel {
+    /// Returns false if the library can't compute with precision `p` bits.
+    pub const fn supports(self, p: usize) -> bool {
+        match self {
+            PrecisionModel::Only(q) => p == q,
+            _ => true,
         }
-        ret
     }
 
-    fn global_state() -> AstroGlobalState {
-        AstroGlobalState {
-            cc: Rc::new(RefCell::new(Consts::new().unwrap()))
+    /// Returns the largest precision in bits the library computes with regardless of the requested one.
+    pub const fn fixed(self) -> Option<usize> {
+        match self {
+            PrecisionModel::Fixed(bits) => Some(bits),
+            // the largest numbers have all bits but the sign bit significant
+            PrecisionModel::FixedPoint { bits, .. } => Some(bits - 1),
+            _ => None,
         }
     }
-
-    fn add(&self, rhs: &Self) -> Self {
-        AstroFloat::new(self.inner().add(rhs.inner(), self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn sub(&self, rhs: &Self) -> Self {
-        AstroFloat::new(self.inner().sub(rhs.inner(), self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn mul(&self, rhs: &Self) -> Self {
-        AstroFloat::new(self.inner().mul(rhs.inner(), self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn div(&self, rhs: &Self) -> Self {
-        AstroFloat::new(self.inner().div(rhs.inner(), self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn sqrt(&self) -> Self {
-        AstroFloat::new(self.inner().sqrt(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn cbrt(&self) -> Self {
-        AstroFloat::new(self.inner().cbrt(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven), self.cc.clone())
-    }
-
-    fn ln(&self) -> Self {
-        AstroFloat::new(self.inner().ln(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn exp(&self) -> Self {
-        AstroFloat::new(self.inner().exp(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn pow(&self, n: &Self) -> Self {
-        AstroFloat::new(self.inner().pow(n.inner(), self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-    
-    fn sin(&self) -> Self {
-        AstroFloat::new(self.inner().sin(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-        
-    fn asin(&self) -> Self {
-        AstroFloat::new(self.inner().asin(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn cos(&self) -> Self {
-        AstroFloat::new(self.inner().cos(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-        
-    fn acos(&self) -> Self {
-        AstroFloat::new(self.inner().acos(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn tan(&self) -> Self {
-        AstroFloat::new(self.inner().tan(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-        
-    fn atan(&self) -> Self {
-        AstroFloat::new(self.inner().atan(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn sinh(&self) -> Self {
-        AstroFloat::new(self.inner().sinh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn asinh(&self) -> Self {
-        AstroFloat::new(self.inner().asinh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn cosh(&self) -> Self {
-        AstroFloat::new(self.inner().cosh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn acosh(&self) -> Self {
-        AstroFloat::new(self.inner().acosh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn tanh(&self) -> Self {
-        AstroFloat::new(self.inner().tanh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
-
-    fn atanh(&self) -> Self {
-        AstroFloat::new(self.inner().atanh(self.inner().mantissa_max_bit_len().unwrap_or(1), astro_float::RoundingMode::ToEven, self.cc.deref().borrow_mut().deref_mut()), self.cc.clone())
-    }
 }
 
-
-impl Number<StubGlobalState> for FBig<HalfEven, 2> {
-    fn rand_normal(n: usize, exp_from: i32, exp_to: i32, _gs: StubGlobalState, sign_positive: bool) -> Vec<Self> {
-        let mut ret = vec![];
-        for _ in 0..n {
-
-            let mut mantissa = [0u64; 3];
-            mantissa.iter_mut().for_each(|v| *v = random());
-
-            if mantissa[mantissa.len() - 1] == 0 {
-                mantissa[mantissa.len() - 1] = u64::MAX;
-            }
-            while mantissa[mantissa.len() - 1] <= (u64::MAX >> 1) {
-                mantissa[mantissa.len() - 1] <<= 1;
-            }
-
-            let exp_range = exp_to - exp_from;
-            let sign = if sign_positive || random::<i8>() & 1 == 0 {dashu_int::Sign::Positive} else {dashu_int::Sign::Negative};
-            let exp = (if exp_range != 0 {random::<i32>().abs() % exp_range} else {0}) - mantissa.len() as i32 * 64 + exp_from;
-            let exp = exp as isize * 3321928095 / 1000000000;
-
-            let m = UBig::from_words(&mantissa);
-            let i = IBig::from_parts(sign, m);
-
-            ret.push(FBig::from_parts(i, exp));
+impl Display for PrecisionModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrecisionModel::Requested => write!(f, "requested precision"),
+            PrecisionModel::Only(q) => write!(f, "{} bits only", q),
+            PrecisionModel::Fixed(bits) => write!(f, "{} bits", bits),
+            PrecisionModel::FixedPoint { bits, frac } => write!(f, "fixed point with {} fractional bits, at most {} bits", frac, bits - 1),
         }
-        ret
-    }
-
-    fn global_state() -> StubGlobalState {
-        StubGlobalState {  }
-    }
-
-    fn add(&self, rhs: &Self) -> Self {
-        <&FBig<dashu_float::round::mode::HalfEven> as Add>::add(self, rhs)
-    }
-
-    fn sub(&self, rhs: &Self) -> Self {
-        <&FBig<dashu_float::round::mode::HalfEven> as Sub>::sub(self, rhs)
-    }
-
-    fn mul(&self, rhs: &Self) -> Self {
-        <&FBig<dashu_float::round::mode::HalfEven> as Mul>::mul(self, rhs)
-    }
-
-    fn div(&self, rhs: &Self) -> Self {
-        <&FBig<dashu_float::round::mode::HalfEven> as Div>::div(self, rhs)
-    }
-
-    fn sqrt(&self) -> Self {
-        let s = self.clone();
-        FBig::<HalfEven, 2>::sqrt(&s)
-    }
-
-    fn cbrt(&self) -> Self {
-        self.clone()
-    }
-
-    fn ln(&self) -> Self {
-        FBig::<HalfEven, 2>::ln(self)
-    }
-
-    fn exp(&self) -> Self {
-        FBig::<HalfEven, 2>::exp(self)
-    }
-
-    fn pow(&self, n: &Self) -> Self {
-        let s = self.clone();
-        FBig::<HalfEven, 2>::powf(&s, n)
-    }
-
-    fn sin(&self) -> Self {
-        self.clone()
-    }
-
-    fn asin(&self) -> Self {
-        self.clone()
-    }
-
-    fn cos(&self) -> Self {
-        self.clone()
     }
+}
 
-    fn acos(&self) -> Self {
-        self.clone()
-    }
+/// Operation with one operand.
+pub(crate) type UnaryOp<T> = fn(&T) -> T;
 
-    fn tan(&self) -> Self {
-        self.clone()
-    }
+/// Operation with two operands.
+pub(crate) type BinaryOp<T> = fn(&T, &T) -> T;
 
-    fn atan(&self) -> Self {
-        self.clone()
+pub(crate) trait Number<G: GlobalState>
+where
+    Self: Sized,
+    Self: Display,
+    Self: Clone,
+{
+    /// Creates a number from `parts` with the precision of `gs`.
+    fn from_parts(parts: &Parts, gs: &G) -> Self;
+
+    fn global_state(p: usize) -> G;
+
+    /// Precision the library computes with.
+    const PRECISION: PrecisionModel = PrecisionModel::Requested;
+
+    /// Range of the exponent `exp` of `Parts` which the library can represent,
+    /// if the range is narrower than the one of the tasks.
+    fn exp_range() -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Returns false if the library doesn't implement the operation of the task,
+    /// i.e. its constant below is `None`.
+    fn supports(task: &str) -> bool {
+        match task {
+            "add" | "sub" | "mul" | "div" => true,
+            "sqrt" => Self::SQRT.is_some(),
+            "cbrt" => Self::CBRT.is_some(),
+            "ln" => Self::LN.is_some(),
+            "exp" => Self::EXP.is_some(),
+            "pow" => Self::POW.is_some(),
+            "sin" => Self::SIN.is_some(),
+            "asin" => Self::ASIN.is_some(),
+            "cos" => Self::COS.is_some(),
+            "acos" => Self::ACOS.is_some(),
+            "tan" => Self::TAN.is_some(),
+            "atan" => Self::ATAN.is_some(),
+            "sinh" => Self::SINH.is_some(),
+            "asinh" => Self::ASINH.is_some(),
+            "cosh" => Self::COSH.is_some(),
+            "acosh" => Self::ACOSH.is_some(),
+            "tanh" => Self::TANH.is_some(),
+            "atanh" => Self::ATANH.is_some(),
+            _ => unreachable!(),
+        }
     }
 
-    fn sinh(&self) -> Self {
-        self.clone()
-    }
+    /// Converts the number to rug float with precision `p` bits
+    /// which is used as the reference for accuracy verification.
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float;
 
-    fn asinh(&self) -> Self {
-        self.clone()
-    }
+    fn add(&self, rhs: &Self) -> Self;
 
-    fn cosh(&self) -> Self {
-        self.clone()
-    }
+    fn sub(&self, rhs: &Self) -> Self;
 
-    fn acosh(&self) -> Self {
-        self.clone()
-    }
+    fn mul(&self, rhs: &Self) -> Self;
 
-    fn tanh(&self) -> Self {
-        self.clone()
-    }
+    fn div(&self, rhs: &Self) -> Self;
 
-    fn atanh(&self) -> Self {
-        self.clone()
-    }
-}
\ No newline at end of file
+    // Operations other than arithmetic, `None` if the library doesn't implement the operation.
+    const SQRT: Option<UnaryOp<Self>> = None;
+    const CBRT: Option<UnaryOp<Self>> = None;
+    const LN: Option<UnaryOp<Self>> = None;
+    const EXP: Option<UnaryOp<Self>> = None;
+    const POW: Option<BinaryOp<Self>> = None;
+    const SIN: Option<UnaryOp<Self>> = None;
+    const ASIN: Option<UnaryOp<Self>> = None;
+    const COS: Option<UnaryOp<Self>> = None;
+    const ACOS: Option<UnaryOp<Self>> = None;
+    const TAN: Option<UnaryOp<Self>> = None;
+    const ATAN: Option<UnaryOp<Self>> = None;
+    const SINH: Option<UnaryOp<Self>> = None;
+    const ASINH: Option<UnaryOp<Self>> = None;
+    const COSH: Option<UnaryOp<Self>> = None;
+    const ACOSH: Option<UnaryOp<Self>> = None;
+    const TANH: Option<UnaryOp<Self>> = None;
+    const ATANH: Option<UnaryOp<Self>> = None;
+}
@@ -0,0 +1,117 @@
+//! Adapter for num-bigfloat.
+
+#[cfg(feature = "rug")]
+use rug::{Float, Integer};
+use crate::number::{BinaryOp, Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_NUM_BIGFLOAT");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, num_bigfloat::BigFloat>("num-bigfloat", VERSION)
+}
+
+/// Precision of num-bigfloat numbers: 40 decimal digits of mantissa.
+const NUM_BIGFLOAT_PRECISION: usize = 132;
+
+impl Number<StubGlobalState> for num_bigfloat::BigFloat {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        let word_base = num_bigfloat::BigFloat::from_u128(1 << 64);
+        let mut ret = num_bigfloat::ZERO;
+        for w in parts.mantissa.iter().rev() {
+            ret = ret.mul(&word_base).add(&num_bigfloat::BigFloat::from_u64(*w));
+        }
+        ret = ret.mul(&num_bigfloat_pow2(parts.int_exp()));
+        if parts.negative { ret.inv_sign() } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::Only(NUM_BIGFLOAT_PRECISION);
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        match self.to_raw_parts() {
+            Some((mantissa, _, sign, exp)) => {
+                let mut m = Integer::new();
+                for d in mantissa.iter().rev() {
+                    m = m * 10000 + *d;
+                }
+                let f = Float::with_val(p, m * sign);
+                let pow = Float::with_val(p, Float::i_pow_u(10, exp.unsigned_abs() as u32));
+                if exp >= 0 { f * pow } else { f / pow }
+            }
+            None if self.is_nan() => Float::with_val(p, rug::float::Special::Nan),
+            None if self.is_inf_pos() => Float::with_val(p, rug::float::Special::Infinity),
+            None => Float::with_val(p, rug::float::Special::NegInfinity),
+        }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        num_bigfloat::BigFloat::add(self, rhs)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        num_bigfloat::BigFloat::sub(self, rhs)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        num_bigfloat::BigFloat::mul(self, rhs)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        num_bigfloat::BigFloat::div(self, rhs)
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| x.sqrt());
+
+    const CBRT: Option<UnaryOp<Self>> = Some(|x| x.cbrt());
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| x.ln());
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| x.exp());
+
+    const POW: Option<BinaryOp<Self>> = Some(num_bigfloat::BigFloat::pow);
+
+    const SIN: Option<UnaryOp<Self>> = Some(|x| x.sin());
+
+    const ASIN: Option<UnaryOp<Self>> = Some(|x| x.asin());
+
+    const COS: Option<UnaryOp<Self>> = Some(|x| x.cos());
+
+    const ACOS: Option<UnaryOp<Self>> = Some(|x| x.acos());
+
+    const TAN: Option<UnaryOp<Self>> = Some(|x| x.tan());
+
+    const ATAN: Option<UnaryOp<Self>> = Some(|x| x.atan());
+
+    const SINH: Option<UnaryOp<Self>> = Some(|x| x.sinh());
+
+    const ASINH: Option<UnaryOp<Self>> = Some(|x| x.asinh());
+
+    const COSH: Option<UnaryOp<Self>> = Some(|x| x.cosh());
+
+    const ACOSH: Option<UnaryOp<Self>> = Some(|x| x.acosh());
+
+    const TANH: Option<UnaryOp<Self>> = Some(|x| x.tanh());
+
+    const ATANH: Option<UnaryOp<Self>> = Some(|x| x.atanh());
+}
+
+/// Returns 2^e.
+fn num_bigfloat_pow2(e: i32) -> num_bigfloat::BigFloat {
+    let mut ret = num_bigfloat::ONE;
+    let mut b = num_bigfloat::TWO;
+    let mut n = e.unsigned_abs();
+    while n > 0 {
+        if n & 1 == 1 {
+            ret = ret.mul(&b);
+        }
+        b = b.mul(&b);
+        n >>= 1;
+    }
+    if e < 0 { num_bigfloat::ONE.div(&ret) } else { ret }
+}
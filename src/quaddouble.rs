@@ -0,0 +1,308 @@
+//! Quad-double numbers: the unevaluated sum of four doubles.
+//!
+//! Arithmetic and square root follow the default (sloppy) algorithms of the QD library
+//! by Hida, Li and Bailey. Other operations are not implemented.
+
+use std::fmt::Display;
+#[cfg(feature = "rug")]
+use rug::Float;
+use crate::number::{Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Version of the implementation, which is a part of this benchmark.
+pub(crate) const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, QuadDouble>("quad-double", VERSION)
+}
+
+/// Precision in bits: four doubles of 53 bits.
+const PRECISION: usize = 212;
+
+#[derive(Clone, Copy)]
+pub struct QuadDouble([f64; 4]);
+
+impl Display for QuadDouble {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c, d] = self.0;
+        write!(f, "{:e} + {:e} + {:e} + {:e}", a, b, c, d)
+    }
+}
+
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+#[inline]
+fn three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+    let (t1, t2) = two_sum(a, b);
+    let (a, t3) = two_sum(c, t1);
+    let (b, c) = two_sum(t2, t3);
+    (a, b, c)
+}
+
+#[inline]
+fn three_sum2(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let (t1, t2) = two_sum(a, b);
+    let (a, t3) = two_sum(c, t1);
+    (a, t2 + t3)
+}
+
+/// Renormalizes five overlapping components into four non-overlapping ones.
+fn renorm(c0: f64, c1: f64, c2: f64, c3: f64, c4: f64) -> QuadDouble {
+    if c0.is_infinite() {
+        return QuadDouble([c0, c1, c2, c3]);
+    }
+
+    let (s, c4) = quick_two_sum(c3, c4);
+    let (s, c3) = quick_two_sum(c2, s);
+    let (s, c2) = quick_two_sum(c1, s);
+    let (c0, c1) = quick_two_sum(c0, s);
+
+    let mut s = [0.0; 4];
+    // fold the remaining components into the next non-zero place
+    let mut k = 0;
+    s[0] = c0;
+    for c in [c1, c2, c3, c4] {
+        let (hi, lo) = quick_two_sum(s[k], c);
+        s[k] = hi;
+        if lo != 0.0 {
+            if k == 3 {
+                s[3] += lo;
+            } else {
+                k += 1;
+                s[k] = lo;
+            }
+        }
+    }
+    QuadDouble(s)
+}
+
+impl QuadDouble {
+    const ZERO: QuadDouble = QuadDouble([0.0; 4]);
+
+    fn from_f64(v: f64) -> Self {
+        QuadDouble([v, 0.0, 0.0, 0.0])
+    }
+
+    fn neg(&self) -> Self {
+        let [a, b, c, d] = self.0;
+        QuadDouble([-a, -b, -c, -d])
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        let (a, b) = (&self.0, &rhs.0);
+        let (s0, t0) = two_sum(a[0], b[0]);
+        let (s1, t1) = two_sum(a[1], b[1]);
+        let (s2, t2) = two_sum(a[2], b[2]);
+        let (s3, t3) = two_sum(a[3], b[3]);
+
+        let (s1, t0) = two_sum(s1, t0);
+        let (s2, t0, t1) = three_sum(s2, t0, t1);
+        let (s3, t0) = three_sum2(s3, t0, t2);
+        let t0 = t0 + t1 + t3;
+
+        renorm(s0, s1, s2, s3, t0)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        let (a, b) = (&self.0, &rhs.0);
+        let (p0, q0) = two_prod(a[0], b[0]);
+        let (p1, q1) = two_prod(a[0], b[1]);
+        let (p2, q2) = two_prod(a[1], b[0]);
+        let (p3, q3) = two_prod(a[0], b[2]);
+        let (p4, q4) = two_prod(a[1], b[1]);
+        let (p5, q5) = two_prod(a[2], b[0]);
+
+        let (p1, p2, q0) = three_sum(p1, p2, q0);
+
+        // six-three sum of p2, q1, q2, p3, p4, p5
+        let (p2, q1, q2) = three_sum(p2, q1, q2);
+        let (p3, p4, p5) = three_sum(p3, p4, p5);
+        let (s0, t0) = two_sum(p2, p3);
+        let (s1, t1) = two_sum(q1, p4);
+        let s2 = q2 + p5;
+        let (s1, t0) = two_sum(s1, t0);
+        let s2 = s2 + (t0 + t1);
+
+        // terms of order eps^3
+        let s1 = s1 + a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0] + q0 + q3 + q4 + q5;
+
+        renorm(p0, p1, s0, s1, s2)
+    }
+
+    /// Multiplication by a double, which is cheaper than the full product.
+    fn mul_f64(&self, b: f64) -> Self {
+        let a = &self.0;
+        let (p0, q0) = two_prod(a[0], b);
+        let (p1, q1) = two_prod(a[1], b);
+        let (p2, q2) = two_prod(a[2], b);
+        let p3 = a[3] * b;
+
+        let (s1, s2) = two_sum(q0, p1);
+        let (s2, q1, p2) = three_sum(s2, q1, p2);
+        let (s3, q2) = three_sum2(q1, q2, p3);
+
+        renorm(p0, s1, s2, s3, q2 + p2)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        let q0 = self.0[0] / rhs.0[0];
+        let r = self.add(&rhs.mul_f64(q0).neg());
+        let q1 = r.0[0] / rhs.0[0];
+        let r = r.add(&rhs.mul_f64(q1).neg());
+        let q2 = r.0[0] / rhs.0[0];
+        let r = r.add(&rhs.mul_f64(q2).neg());
+        let q3 = r.0[0] / rhs.0[0];
+        renorm(q0, q1, q2, q3, 0.0)
+    }
+
+    fn sqrt(&self) -> Self {
+        if self.0[0] == 0.0 {
+            return QuadDouble::ZERO;
+        }
+        if self.0[0] < 0.0 {
+            return QuadDouble::from_f64(f64::NAN);
+        }
+
+        // Newton iterations for 1/sqrt(a): r += (0.5 - a/2 * r^2) * r,
+        // the initial approximation is a double, so the first iteration multiplies by doubles
+        let r0 = 1.0 / self.0[0].sqrt();
+        let h = QuadDouble(self.0.map(|v| v * 0.5));
+        let half = QuadDouble::from_f64(0.5);
+        let d = half.add(&h.mul_f64(r0).mul_f64(r0).neg());
+        let mut r = QuadDouble::from_f64(r0).add(&d.mul_f64(r0));
+        for _ in 0..2 {
+            let d = half.add(&h.mul(&r.mul(&r)).neg());
+            r = r.add(&d.mul(&r));
+        }
+        r.mul(self)
+    }
+}
+
+impl Number<StubGlobalState> for QuadDouble {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        // the four most significant words are enough for 212 bits,
+        // each of them is split in halves which are exact in f64
+        let mut ret = QuadDouble::ZERO;
+        let mut e = parts.exp;
+        for w in parts.mantissa.iter().rev().take(4) {
+            for half in [w >> 32, w & 0xffffffff] {
+                e -= 32;
+                ret = ret.add(&QuadDouble::from_f64(half as f64 * 2f64.powi(e)));
+            }
+        }
+        if parts.negative { ret.neg() } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::Fixed(PRECISION);
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        let [a, b, c, d] = self.0;
+        Float::with_val(p, a) + b + c + d
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        QuadDouble::add(self, rhs)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        QuadDouble::add(self, &rhs.neg())
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        QuadDouble::mul(self, rhs)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        QuadDouble::div(self, rhs)
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(QuadDouble::sqrt);
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use super::*;
+
+    /// Allowed relative error of a single operation.
+    const MAX_ERROR: f64 = 1.5e-63; // about 2^-209
+
+    /// Returns random numbers with exponents from `exp_from` to `exp_to` with base 10.
+    fn operands(n: usize, exp_from: i32, exp_to: i32, sign_positive: bool) -> Vec<QuadDouble> {
+        let mut rng = StdRng::seed_from_u64(n as u64);
+        let gs = StubGlobalState::new(PRECISION);
+        (0..n)
+            .map(|_| QuadDouble::from_parts(&Parts::random_normal(&mut rng, PRECISION, exp_from, exp_to, sign_positive), &gs))
+            .collect()
+    }
+
+    /// Returns the difference of `x` and `y` relative to `y`.
+    fn rel_diff(x: &QuadDouble, y: &QuadDouble) -> f64 {
+        x.add(&y.neg()).0[0].abs() / y.0[0].abs()
+    }
+
+    #[test]
+    fn add_sub_round_trip() {
+        // exponents are close, so that the subtraction doesn't cancel most of the digits
+        let v = operands(2000, 0, 1, false);
+        for (a, b) in v[..1000].iter().zip(&v[1000..]) {
+            let r = a.add(b).add(&b.neg());
+            assert!(rel_diff(&r, a) < 16.0 * MAX_ERROR, "({} + {}) - b", a, b);
+        }
+    }
+
+    #[test]
+    fn mul_div_round_trip() {
+        let v = operands(2000, -10, 10, false);
+        for (a, b) in v[..1000].iter().zip(&v[1000..]) {
+            assert!(rel_diff(&a.mul(b).div(b), a) < 2.0 * MAX_ERROR, "({} * {}) / b", a, b);
+            assert!(rel_diff(&a.mul_f64(b.0[0]).div(&QuadDouble::from_f64(b.0[0])), a) < 2.0 * MAX_ERROR, "({} * {}) / b", a, b);
+        }
+    }
+
+    #[test]
+    fn sqrt_round_trip() {
+        for a in operands(1000, -10, 10, true) {
+            let r = a.sqrt();
+            assert!(rel_diff(&r.mul(&r), &a) < 3.0 * MAX_ERROR, "sqrt({})^2", a);
+        }
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn matches_rug() {
+        let p = 2 * PRECISION as u32;
+        let rel_error = |res: &QuadDouble, reference: Float| ((res.to_reference(p) - &reference) / reference).abs().to_f64();
+        let v = operands(2000, -10, 10, true);
+        for (a, b) in v[..1000].iter().zip(&v[1000..]) {
+            let (ra, rb) = (a.to_reference(p), b.to_reference(p));
+            assert!(rel_error(&a.add(b), Float::with_val(p, &ra + &rb)) < MAX_ERROR, "{} + {}", a, b);
+            assert!(rel_error(&a.mul(b), Float::with_val(p, &ra * &rb)) < MAX_ERROR, "{} * {}", a, b);
+            assert!(rel_error(&a.mul_f64(b.0[0]), Float::with_val(p, &ra * b.0[0])) < MAX_ERROR, "{} * {}", a, b.0[0]);
+            assert!(rel_error(&a.div(b), Float::with_val(p, &ra / &rb)) < MAX_ERROR, "{} / {}", a, b);
+            assert!(rel_error(&a.sqrt(), ra.sqrt()) < MAX_ERROR, "sqrt({})", a);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Registry of the benchmarked libraries.
+//!
+//! Each adapter module registers its libraries with `inventory::submit!`,
+//! so the library dispatch doesn't list them. Adding a library also needs
+//! its `mod` declaration in main.rs behind a cargo feature declared in Cargo.toml.
+
+use crate::number::{GlobalState, Number, Parts, PrecisionModel};
+use crate::report::{LibInfo, Outcome};
+use crate::Options;
+
+/// A library which can be selected with `--lib`.
+pub(crate) struct Backend {
+    pub name: &'static str,
+    /// Resolved version of the library.
+    pub version: &'static str,
+    /// Precision the library computes with.
+    pub precision: PrecisionModel,
+    /// Returns false if the library doesn't implement the operation of the task.
+    pub supports: fn(&str) -> bool,
+    /// Runs the task on the operands with precision `p` bits.
+    pub run: fn(task: &str, corpus: &[Parts], p: usize, opts: &Options) -> Outcome,
+}
+
+impl Backend {
+    /// Creates a backend which describes and runs the tasks with the `Number` implementation `T`.
+    pub const fn new<G: GlobalState, T: Number<G>>(name: &'static str, version: &'static str) -> Self {
+        Backend {
+            name,
+            version,
+            precision: T::PRECISION,
+            supports: T::supports,
+            run: crate::benchmark_lib_task::<G, T>,
+        }
+    }
+
+    pub fn info(&self) -> LibInfo<'static> {
+        LibInfo { name: self.name, version: self.version, precision: self.precision }
+    }
+}
+
+inventory::collect!(Backend);
+
+/// Returns all registered backends ordered by name.
+pub(crate) fn backends() -> Vec<&'static Backend> {
+    let mut ret: Vec<&'static Backend> = inventory::iter::<Backend>.into_iter().collect();
+    ret.sort_by_key(|b| b.name);
+    ret
+}
+
+/// Returns the backend registered with `name`.
+pub(crate) fn find(name: &str) -> &'static Backend {
+    inventory::iter::<Backend>.into_iter().find(|b| b.name == name).expect("unknown library")
+}
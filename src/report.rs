@@ -0,0 +1,359 @@
+//! Benchmark results and output formats.
+
+use std::time::Duration;
+use crate::number::PrecisionModel;
+use crate::stats::{summarize, Statistic, Summary, CONFIDENCE};
+use crate::tasks::{op_count, task_description, task_range};
+use crate::verify::Accuracy;
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            "csv" => Format::Csv,
+            "markdown" => Format::Markdown,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Benchmarked library.
+pub(crate) struct LibInfo<'a> {
+    pub name: &'a str,
+    /// Resolved crate version.
+    pub version: &'static str,
+    /// Precision the library computes with.
+    pub precision: PrecisionModel,
+}
+
+impl LibInfo<'_> {
+    /// Returns the version, annotated with the fixed precision of the library
+    /// if it is lower than `p`.
+    pub fn version_note(&self, p: usize) -> String {
+        match (self.precision, self.precision.fixed()) {
+            (PrecisionModel::FixedPoint { frac, .. }, Some(fp)) if fp < p => {
+                format!("{}, at most {} bits, {} fractional", self.version, fp, frac)
+            }
+            (_, Some(fp)) if fp < p => format!("{}, {} bits", self.version, fp),
+            _ => self.version.to_string(),
+        }
+    }
+}
+
+/// Parameters of a benchmark run.
+pub(crate) struct RunInfo<'a> {
+    pub libs: Vec<LibInfo<'a>>,
+    pub precisions: &'a [usize],
+    pub n: usize,
+    pub seed: u64,
+}
+
+pub(crate) struct TaskResult {
+    pub lib: String,
+    /// Resolved crate version of the library.
+    pub version: &'static str,
+    pub task: String,
+    pub precision: usize,
+    pub outcome: Outcome,
+}
+
+pub(crate) enum Outcome {
+    /// The library can't compute with the requested precision
+    /// or doesn't implement the operation.
+    NotApplicable,
+    /// The measurement was aborted after `--timeout`.
+    Timeout,
+    /// The process of the measurement exited abnormally, e.g. the library panicked.
+    Failed,
+    Measured(Measurement),
+}
+
+pub(crate) struct Measurement {
+    /// Mean duration of a task run in each round.
+    pub durations: Vec<Duration>,
+    /// Number of operations in a task run.
+    pub ops: usize,
+    /// Duration of the first run of the task, before warm-up.
+    pub cold: Duration,
+    pub accuracy: Option<Accuracy>,
+    /// Result of the scaling self-check, 1 is the expected value.
+    pub scaling: Option<f64>,
+    /// Statistic returned by `time`.
+    pub statistic: Statistic,
+    pub summary: Summary,
+}
+
+impl Measurement {
+    pub fn new(durations: Vec<Duration>, ops: usize, cold: Duration, accuracy: Option<Accuracy>, scaling: Option<f64>, statistic: Statistic) -> Self {
+        let summary = summarize(&durations, statistic);
+        Measurement {
+            durations,
+            ops,
+            cold,
+            accuracy,
+            scaling,
+            statistic,
+            summary,
+        }
+    }
+
+    /// Duration of a task run in nanoseconds reported as the result.
+    pub fn time_ns(&self) -> f64 {
+        match self.statistic {
+            Statistic::Min => self.summary.min,
+            Statistic::Median => self.summary.median,
+            Statistic::Mean => self.summary.mean,
+        }
+    }
+
+    pub fn ns_per_op(&self) -> f64 {
+        self.time_ns() / self.ops as f64
+    }
+
+    pub fn cold_ns_per_op(&self) -> f64 {
+        self.cold.as_nanos() as f64 / self.ops as f64
+    }
+}
+
+impl TaskResult {
+    /// Text representation used in the text table: time per operation
+    /// with the half-width of the confidence interval, and accuracy.
+    pub fn text(&self) -> String {
+        match &self.outcome {
+            Outcome::NotApplicable => "n/a".to_string(),
+            Outcome::Timeout => "timeout".to_string(),
+            Outcome::Failed => "failed".to_string(),
+            Outcome::Measured(m) => {
+                let mut ret = format!("{} ns", fmt_significant(m.ns_per_op()));
+                if m.durations.len() > 1 {
+                    let (lo, hi) = m.summary.ci;
+                    ret += &format!(" ±{:.1}%", (hi - lo) / 2.0 / m.time_ns() * 100.0);
+                }
+                ret += &format!(", cold {} ns", fmt_significant(m.cold_ns_per_op()));
+                if let Some(accuracy) = &m.accuracy {
+                    ret += &format!(", {}", accuracy);
+                }
+                if let Some(scaling) = m.scaling {
+                    ret += &format!(", scaling {:.2}", scaling);
+                }
+                ret
+            }
+        }
+    }
+
+    /// Summary statistics of the rounds per operation, if the task was measured.
+    pub fn statistics(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Measured(m) => {
+                let per_op = |v: f64| fmt_significant(v / m.ops as f64);
+                Some(format!("min {} ns, median {} ns, mean {} ns, std dev {} ns, {} outliers",
+                    per_op(m.summary.min), per_op(m.summary.median), per_op(m.summary.mean), per_op(m.summary.std_dev), m.summary.outliers))
+            }
+            _ => None,
+        }
+    }
+
+    /// Duration of a task run only.
+    pub fn headline(&self) -> String {
+        match &self.outcome {
+            Outcome::NotApplicable => "n/a".to_string(),
+            Outcome::Timeout => "timeout".to_string(),
+            Outcome::Failed => "failed".to_string(),
+            Outcome::Measured(m) => format!("{} ms", fmt_significant(m.time_ns() / 1e6)),
+        }
+    }
+}
+
+/// Formats `v` with at least three significant digits.
+fn fmt_significant(v: f64) -> String {
+    if v < 10.0 {
+        format!("{:.2}", v)
+    } else if v < 100.0 {
+        format!("{:.1}", v)
+    } else {
+        format!("{:.0}", v)
+    }
+}
+
+pub(crate) fn print_json(info: &RunInfo, results: &[TaskResult]) {
+    println!("{{");
+    println!("  \"seed\": {},", info.seed);
+    println!("  \"n\": {},", info.n);
+    println!("  \"precisions\": [{}],", info.precisions.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", "));
+    println!("  \"host\": {{");
+    println!("    \"os\": {},", json_str(std::env::consts::OS));
+    println!("    \"arch\": {},", json_str(std::env::consts::ARCH));
+    println!("    \"cpus\": {}", std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1));
+    println!("  }},");
+    println!("  \"libs\": [");
+    for (i, lib) in info.libs.iter().enumerate() {
+        let sep = if i + 1 < info.libs.len() { "," } else { "" };
+        let fixed = match lib.precision.fixed() {
+            Some(fp) => format!(", \"fixed_precision\": {}", fp),
+            None => String::new(),
+        };
+        println!("    {{\"name\": {}, \"version\": {}{}}}{}", json_str(lib.name), json_str(lib.version), fixed, sep);
+    }
+    println!("  ],");
+    println!("  \"results\": [");
+    for (i, r) in results.iter().enumerate() {
+        let sep = if i + 1 < results.len() { "," } else { "" };
+        println!("    {}{}", json_result(r), sep);
+    }
+    println!("  ]");
+    println!("}}");
+}
+
+/// Prints a table for each precision in the layout of README.md.
+pub(crate) fn print_markdown(info: &RunInfo, results: &[TaskResult]) {
+    println!("Seed: {}", info.seed);
+    println!();
+    for &p in info.precisions {
+        println!("Results for the precision {} bits:", fmt_thousands(p));
+        println!();
+
+        print!("| {: <45} |", "Task");
+        for lib in &info.libs {
+            print!(" {} ({}) |", lib.name, lib.version_note(p));
+        }
+        println!();
+        print!("| {: <45} |", "--------------");
+        for _ in &info.libs {
+            print!(" ------- |");
+        }
+        println!();
+
+        let table: Vec<&TaskResult> = results.iter().filter(|r| r.precision == p).collect();
+        for row in table.chunks(info.libs.len()) {
+            let task = &row[0].task;
+            let ops = op_count(task, task_range(task, p).0);
+            let name = format!("{} of {}", fmt_thousands(ops), task_description(task));
+            print!("| {: <45} |", name);
+            for r in row {
+                print!(" {: >9} |", r.headline());
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+/// Formats `n` with comma as a thousands separator.
+fn fmt_thousands(n: usize) -> String {
+    let s = n.to_string();
+    let mut ret = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if i > 0 && (s.len() - i).is_multiple_of(3) {
+            ret.push(',');
+        }
+        ret.push(c);
+    }
+    ret
+}
+
+/// Prints a row for each round of each library, task and precision.
+pub(crate) fn print_csv(info: &RunInfo, results: &[TaskResult]) {
+    println!("lib,version,task,precision,seed,status,iteration,ops,elapsed_us,ns_per_op,\
+        min_ns_per_op,median_ns_per_op,mean_ns_per_op,std_dev_ns_per_op,outliers");
+    for r in results {
+        let key = format!("{},{},{},{},{}", csv_str(&r.lib), csv_str(r.version), csv_str(&r.task), r.precision, info.seed);
+        match &r.outcome {
+            Outcome::NotApplicable => println!("{},n/a,,,,,,,,,", key),
+            Outcome::Timeout => println!("{},timeout,,,,,,,,,", key),
+            Outcome::Failed => println!("{},failed,,,,,,,,,", key),
+            Outcome::Measured(m) => {
+                // the statistics of all rounds are repeated in each row of the lib/task
+                let ops = m.ops as f64;
+                let s = &m.summary;
+                let stats = format!("{},{},{},{},{}", s.min / ops, s.median / ops, s.mean / ops, s.std_dev / ops, s.outliers);
+                let ns = m.cold.as_nanos() as f64;
+                println!("{},cold,0,{},{},{},{}", key, m.ops, ns / 1000.0, ns / ops, stats);
+                for (i, d) in m.durations.iter().enumerate() {
+                    let ns = d.as_nanos() as f64;
+                    println!("{},ok,{},{},{},{},{}", key, i + 1, m.ops, ns / 1000.0, ns / ops, stats);
+                }
+            }
+        }
+    }
+}
+
+fn csv_str(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn json_result(r: &TaskResult) -> String {
+    let mut fields = vec![
+        format!("\"lib\": {}", json_str(&r.lib)),
+        format!("\"version\": {}", json_str(r.version)),
+        format!("\"task\": {}", json_str(&r.task)),
+        format!("\"precision\": {}", r.precision),
+    ];
+    match &r.outcome {
+        Outcome::NotApplicable => fields.push("\"status\": \"n/a\"".to_string()),
+        Outcome::Timeout => fields.push("\"status\": \"timeout\"".to_string()),
+        Outcome::Failed => fields.push("\"status\": \"failed\"".to_string()),
+        Outcome::Measured(m) => {
+            // each round runs the task several times, so its duration is the mean of the runs
+            let durations: Vec<String> = m.durations.iter().map(|d| d.as_nanos().to_string()).collect();
+            fields.push("\"status\": \"ok\"".to_string());
+            fields.push(format!("\"ops\": {}", m.ops));
+            fields.push(format!("\"round_means_ns\": [{}]", durations.join(", ")));
+            fields.push(format!("\"statistic\": {}", json_str(m.statistic.name())));
+            fields.push(format!("\"time_ns\": {}", json_f64(m.time_ns())));
+            fields.push(format!("\"ns_per_op\": {}", json_f64(m.ns_per_op())));
+            fields.push(format!("\"cold_ns\": {}", m.cold.as_nanos()));
+            fields.push(format!("\"cold_ns_per_op\": {}", json_f64(m.cold_ns_per_op())));
+            fields.push(format!("\"min_ns\": {}", json_f64(m.summary.min)));
+            fields.push(format!("\"median_ns\": {}", json_f64(m.summary.median)));
+            fields.push(format!("\"mean_ns\": {}", json_f64(m.summary.mean)));
+            fields.push(format!("\"std_dev_ns\": {}", json_f64(m.summary.std_dev)));
+            fields.push(format!("\"confidence\": {}", CONFIDENCE));
+            fields.push(format!("\"ci_ns\": [{}, {}]", json_f64(m.summary.ci.0), json_f64(m.summary.ci.1)));
+            fields.push(format!("\"outliers\": {}", m.summary.outliers));
+            if let Some(accuracy) = &m.accuracy {
+                fields.push(format!("\"max_error_ulp\": {}", json_f64(accuracy.max)));
+                fields.push(format!("\"mean_error_ulp\": {}", json_f64(accuracy.mean)));
+            }
+            if let Some(scaling) = m.scaling {
+                fields.push(format!("\"scaling\": {}", json_f64(scaling)));
+            }
+        }
+    }
+    format!("{{{}}}", fields.join(", "))
+}
+
+fn json_str(s: &str) -> String {
+    let mut ret = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            c if (c as u32) < 0x20 => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+/// JSON has no infinity and NaN, they are represented by null.
+fn json_f64(v: f64) -> String {
+    if v.is_finite() {
+        format!("{}", v)
+    } else {
+        "null".to_string()
+    }
+}
@@ -0,0 +1,86 @@
+//! Adapter for rust_decimal.
+//!
+//! Decimal has no infinity, so results which overflow its range saturate to `Decimal::MAX`.
+
+use rust_decimal::{Decimal, MathematicalOps};
+#[cfg(feature = "rug")]
+use rug::{Float, Integer};
+use crate::number::{BinaryOp, Number, Parts, PrecisionModel, StubGlobalState, UnaryOp};
+use crate::registry::Backend;
+
+/// Resolved version of the crate.
+pub(crate) const VERSION: &str = env!("VERSION_RUST_DECIMAL");
+
+inventory::submit! {
+    Backend::new::<StubGlobalState, Decimal>("rust_decimal", VERSION)
+}
+
+/// Number of significant decimal digits of Decimal.
+const DIGITS: u32 = 28;
+
+/// Largest power of two which fits in the 96-bit mantissa and is used for scaling.
+const SCALE_STEP: i32 = 64;
+
+fn saturate(d: Option<Decimal>) -> Decimal {
+    d.unwrap_or(Decimal::MAX)
+}
+
+impl Number<StubGlobalState> for Decimal {
+    fn from_parts(parts: &Parts, _gs: &StubGlobalState) -> Self {
+        // the 96 most significant bits of the mantissa scaled by powers of two
+        let words = parts.mantissa.len();
+        let top = parts.mantissa[words - 1] as u128;
+        let next = if words > 1 { parts.mantissa[words - 2] as u128 } else { 0 };
+        let mut ret = Decimal::from_i128_with_scale((((top << 64) | next) >> 32) as i128, 0);
+        let mut e = parts.exp - 96;
+        while e != 0 {
+            let k = e.abs().min(SCALE_STEP);
+            let step = Decimal::from(1u128 << k);
+            ret = saturate(if e < 0 { ret.checked_div(step) } else { ret.checked_mul(step) });
+            e -= k * e.signum();
+        }
+        if parts.negative { -ret } else { ret }
+    }
+
+    fn global_state(p: usize) -> StubGlobalState {
+        StubGlobalState::new(p)
+    }
+
+    const PRECISION: PrecisionModel = PrecisionModel::Fixed((DIGITS as f64 / std::f64::consts::LOG10_2) as usize);
+
+    #[cfg(feature = "rug")]
+    fn to_reference(&self, p: u32) -> Float {
+        let ret = Float::with_val(p, Integer::from(self.mantissa()));
+        ret / Integer::from(Integer::u_pow_u(10, self.scale()))
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        saturate(self.checked_add(*rhs))
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        saturate(self.checked_sub(*rhs))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        saturate(self.checked_mul(*rhs))
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        saturate(self.checked_div(*rhs))
+    }
+
+    const SQRT: Option<UnaryOp<Self>> = Some(|x| saturate(MathematicalOps::sqrt(x)));
+
+    const LN: Option<UnaryOp<Self>> = Some(|x| saturate(x.checked_ln()));
+
+    const EXP: Option<UnaryOp<Self>> = Some(|x| saturate(x.checked_exp()));
+
+    const POW: Option<BinaryOp<Self>> = Some(|x, n| saturate(x.checked_powd(*n)));
+
+    const SIN: Option<UnaryOp<Self>> = Some(|x| saturate(x.checked_sin()));
+
+    const COS: Option<UnaryOp<Self>> = Some(|x| saturate(x.checked_cos()));
+
+    const TAN: Option<UnaryOp<Self>> = Some(|x| saturate(x.checked_tan()));
+}
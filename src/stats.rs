@@ -0,0 +1,123 @@
+//! Statistics of the durations measured in benchmark rounds.
+
+use std::time::Duration;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Number of resamples used to compute the bootstrap confidence interval.
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+
+/// Confidence level of the bootstrap confidence interval.
+pub(crate) const CONFIDENCE: f64 = 0.95;
+
+/// Statistic reported as the result of a benchmark.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Statistic {
+    Min,
+    Median,
+    Mean,
+}
+
+impl Statistic {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "min" => Statistic::Min,
+            "median" => Statistic::Median,
+            "mean" => Statistic::Mean,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Statistic::Min => "min",
+            Statistic::Median => "median",
+            Statistic::Mean => "mean",
+        }
+    }
+
+    /// Computes the statistic of sorted values.
+    fn compute(&self, sorted: &[f64]) -> f64 {
+        match self {
+            Statistic::Min => sorted[0],
+            Statistic::Median => quantile(sorted, 0.5),
+            Statistic::Mean => sorted.iter().sum::<f64>() / sorted.len() as f64,
+        }
+    }
+}
+
+/// Summary of durations in nanoseconds.
+pub(crate) struct Summary {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    /// Bootstrap confidence interval of the reported statistic.
+    pub ci: (f64, f64),
+    /// Number of values outside of the Tukey's fences with k = 1.5.
+    pub outliers: usize,
+}
+
+/// Relative standard error of the mean of durations.
+pub(crate) fn relative_std_error(durations: &[Duration]) -> f64 {
+    let v: Vec<f64> = durations.iter().map(|d| d.as_nanos() as f64).collect();
+    if v.len() < 2 {
+        return f64::INFINITY;
+    }
+    let mean = v.iter().sum::<f64>() / v.len() as f64;
+    let var = v.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (v.len() - 1) as f64;
+    (var / v.len() as f64).sqrt() / mean
+}
+
+pub(crate) fn summarize(durations: &[Duration], statistic: Statistic) -> Summary {
+    let mut v: Vec<f64> = durations.iter().map(|d| d.as_nanos() as f64).collect();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mean = Statistic::Mean.compute(&v);
+    let std_dev = if v.len() > 1 {
+        (v.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (v.len() - 1) as f64).sqrt()
+    } else {
+        0.0
+    };
+
+    let q1 = quantile(&v, 0.25);
+    let q3 = quantile(&v, 0.75);
+    let iqr = q3 - q1;
+    let outliers = v.iter().filter(|&&x| x < q1 - 1.5 * iqr || x > q3 + 1.5 * iqr).count();
+
+    Summary {
+        min: v[0],
+        median: quantile(&v, 0.5),
+        mean,
+        std_dev,
+        ci: bootstrap_ci(&v, statistic),
+        outliers,
+    }
+}
+
+/// Returns the confidence interval of the statistic using the percentile bootstrap.
+fn bootstrap_ci(v: &[f64], statistic: Statistic) -> (f64, f64) {
+    // fixed seed keeps the interval reproducible for the same measurements
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut sample = vec![0.0; v.len()];
+    let mut estimates = Vec::with_capacity(BOOTSTRAP_RESAMPLES);
+    for _ in 0..BOOTSTRAP_RESAMPLES {
+        sample.iter_mut().for_each(|x| *x = v[rng.gen_range(0..v.len())]);
+        sample.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        estimates.push(statistic.compute(&sample));
+    }
+    estimates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let alpha = (1.0 - CONFIDENCE) / 2.0;
+    (quantile(&estimates, alpha), quantile(&estimates, 1.0 - alpha))
+}
+
+/// Returns the quantile `q` of sorted values using linear interpolation.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let i = pos.floor() as usize;
+    let frac = pos - i as f64;
+    if i + 1 < sorted.len() {
+        sorted[i] + (sorted[i + 1] - sorted[i]) * frac
+    } else {
+        sorted[i]
+    }
+}
@@ -1,12 +1,21 @@
-use crate::number::{Number, GlobalState};
+use std::hint::black_box;
+use crate::number::{GlobalState, Number};
+#[cfg(feature = "rug")]
+use crate::number::{BinaryOp, UnaryOp};
 
+/// Names of all tasks.
+pub(crate) const TASKS: &[&str] = &["add", "sub", "mul", "div", "sqrt", "cbrt", "ln", "exp", "pow",
+    "sin", "asin", "cos", "acos", "tan", "atan", "sinh", "asinh", "cosh", "acosh", "tanh", "atanh"];
+
+/// Number of passes over the operand pairs in `task_for_two_args`.
+const TWO_ARGS_PASSES: usize = 2;
 
 pub(crate) fn task_for_two_args<G: GlobalState, T: Number<G>>(n: &[T], op: impl Fn(&T, &T) -> T) -> T {
     let mut f = n[0].clone();
     let (s1, s2) = n.split_at(n.len() / 2);
-    for _ in 0..2 {
+    for _ in 0..TWO_ARGS_PASSES {
         for (u, v) in s1.iter().zip(s2) {
-            f = op(u, v);
+            f = black_box(op(black_box(u), black_box(v)));
         }
     }
     f
@@ -15,7 +24,145 @@ pub(crate) fn task_for_two_args<G: GlobalState, T: Number<G>>(n: &[T], op: impl
 pub(crate) fn task_for_one_arg<G: GlobalState, T: Number<G>>(n: &[T], op: impl Fn(&T) -> T) -> T {
     let mut f = n[0].clone();
     for v in n.iter() {
-        f = op(v);
+        f = black_box(op(black_box(v)));
     }
     f
 }
+
+/// Returns the number of operands at precision `p` bits, the range of their exponent,
+/// and whether the operands must be positive.
+pub(crate) fn task_range(task: &str, p: usize) -> (usize, i32, i32, bool) {
+    // exponent has base 10
+    let (n, exp_from, exp_to, sign_positive) = match task {
+        "add" => (1000000, -10, 10, false),
+        "sub" => (1000000, -10, 10, false),
+        "mul" => (1000000, -10, 10, false),
+        "div" => (1000000, -10, 10, false),
+        "sqrt" => (100000, -10, 10, true),
+        "cbrt" => (100000, -10, 10, false),
+        "ln" => (10000, -10, 10, true),
+        "exp" => (10000, -10, 3, false),
+        "pow" => (10000, -5, 5, false),
+        "sin" => (10000, -10, 3, false),
+        "cos" => (10000, -10, 3, false),
+        "tan" => (10000, -10, 3, false),
+        "sinh" => (10000, -10, 3, false),
+        "cosh" => (10000, -10, 3, false),
+        "tanh" => (10000, -10, 3, false),
+        "asin" => (10000, -10, 0, false),
+        "acos" => (10000, -10, 0, false),
+        "atan" => (10000, -10, 0, false),
+        "asinh" => (10000, -10, 10, false),
+        "acosh" => (10000, 1, 10, true),
+        "atanh" => (10000, -10, 0, false),
+        _ => unreachable!(),
+    };
+    ((n / operand_divisor(task, p)).max(2), exp_from, exp_to, sign_positive)
+}
+
+/// Returns by how much the number of operands is reduced at precision `p` bits.
+/// The counts match the tables in README.md at 132, 1,000 and 10,000 bits,
+/// and above 10,000 bits decrease in proportion to the precision.
+fn operand_divisor(task: &str, p: usize) -> usize {
+    match p {
+        0..=999 => 1,
+        1000..=9999 => 10,
+        _ => {
+            let d = match task {
+                "add" | "sub" | "mul" | "div" | "sqrt" => 10,
+                _ => 100,
+            };
+            d * (p / 10000)
+        }
+    }
+}
+
+/// Narrows the range `min`..=`max` of the exponent of operands a library can represent,
+/// so that the results of the task are also in the range.
+pub(crate) fn clip_exp_range(task: &str, (min, max): (i32, i32)) -> (i32, i32) {
+    match task {
+        // a sum can be twice as large as the operands
+        "add" | "sub" => (min, max - 1),
+        // the exponent of the product is the sum of the exponents or less by one
+        "mul" => (min / 2 + 1, max / 2),
+        // the exponent of the quotient is the difference of the exponents or greater by one,
+        // so the width of the range is limited by both the largest and the smallest exponent
+        "div" => {
+            let w = (max - 1).min(-min);
+            (-(w / 2), w - w / 2)
+        }
+        _ => (min, max),
+    }
+}
+
+/// Returns the number of operations performed by a task run on `n` operands.
+pub(crate) fn op_count(task: &str, n: usize) -> usize {
+    match task {
+        "add" | "sub" | "mul" | "div" | "pow" => n / 2 * TWO_ARGS_PASSES,
+        _ => n,
+    }
+}
+
+/// Returns a human readable description of the task operations.
+pub(crate) fn task_description(task: &str) -> &'static str {
+    match task {
+        "add" => "additions",
+        "sub" => "subtractions",
+        "mul" => "multiplications",
+        "div" => "divisions",
+        "sqrt" => "square root computations",
+        "cbrt" => "cube root computations",
+        "ln" => "natural logarithm computations",
+        "exp" => "e^n computations",
+        "pow" => "power computations",
+        "sin" => "sine computations",
+        "cos" => "cosine computations",
+        "tan" => "tangent computations",
+        "sinh" => "hyperbolic sine computations",
+        "cosh" => "hyperbolic cosine computations",
+        "tanh" => "hyperbolic tangent computations",
+        "asin" => "arcsine computations",
+        "acos" => "arccosine computations",
+        "atan" => "arctangent computations",
+        "asinh" => "hyperbolic arcsine computations",
+        "acosh" => "hyperbolic arccosine computations",
+        "atanh" => "hyperbolic arctangent computations",
+        _ => unreachable!(),
+    }
+}
+
+/// A single operation of a task.
+#[cfg(feature = "rug")]
+pub(crate) enum Op<T> {
+    Unary(UnaryOp<T>),
+    Binary(BinaryOp<T>),
+}
+
+/// Returns the operation of the task, or `None` if `T` doesn't implement it.
+#[cfg(feature = "rug")]
+pub(crate) fn op_for_task<G: GlobalState, T: Number<G>>(task: &str) -> Option<Op<T>> {
+    match task {
+        "add" => Some(Op::Binary(T::add)),
+        "sub" => Some(Op::Binary(T::sub)),
+        "mul" => Some(Op::Binary(T::mul)),
+        "div" => Some(Op::Binary(T::div)),
+        "sqrt" => T::SQRT.map(Op::Unary),
+        "cbrt" => T::CBRT.map(Op::Unary),
+        "ln" => T::LN.map(Op::Unary),
+        "exp" => T::EXP.map(Op::Unary),
+        "pow" => T::POW.map(Op::Binary),
+        "sin" => T::SIN.map(Op::Unary),
+        "asin" => T::ASIN.map(Op::Unary),
+        "cos" => T::COS.map(Op::Unary),
+        "acos" => T::ACOS.map(Op::Unary),
+        "tan" => T::TAN.map(Op::Unary),
+        "atan" => T::ATAN.map(Op::Unary),
+        "sinh" => T::SINH.map(Op::Unary),
+        "asinh" => T::ASINH.map(Op::Unary),
+        "cosh" => T::COSH.map(Op::Unary),
+        "acosh" => T::ACOSH.map(Op::Unary),
+        "tanh" => T::TANH.map(Op::Unary),
+        "atanh" => T::ATANH.map(Op::Unary),
+        _ => unreachable!(),
+    }
+}
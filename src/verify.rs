@@ -0,0 +1,84 @@
+//! Accuracy verification of task results against rug computing with extra precision.
+
+use std::fmt::Display;
+#[cfg(feature = "rug")]
+use rug::Float;
+#[cfg(feature = "rug")]
+use crate::number::{Number, GlobalState, StubGlobalState};
+#[cfg(feature = "rug")]
+use crate::tasks::{op_for_task, Op};
+
+/// Number of extra bits of precision used to compute the reference results.
+#[cfg(feature = "rug")]
+const EXTRA_PRECISION: u32 = 64;
+
+/// Error of task results in ULPs of the benchmark precision.
+pub(crate) struct Accuracy {
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl Display for Accuracy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "max {}, mean {} ulp", fmt_ulp(self.max), fmt_ulp(self.mean))
+    }
+}
+
+fn fmt_ulp(v: f64) -> String {
+    if v.is_infinite() {
+        "inf".to_string()
+    } else if v >= 10000.0 {
+        format!("{:.1e}", v)
+    } else {
+        format!("{:.2}", v)
+    }
+}
+
+/// Computes each operation of the task with `T` and with rug at precision `p` + `EXTRA_PRECISION`,
+/// and returns the error of `T` results relative to the rug results.
+#[cfg(feature = "rug")]
+pub(crate) fn verify_task<G: GlobalState, T: Number<G>>(task: &str, vals: &[T], p: usize) -> Accuracy {
+    let rp = p as u32 + EXTRA_PRECISION;
+    let mut errors = vec![];
+
+    match (op_for_task::<G, T>(task), op_for_task::<StubGlobalState, Float>(task)) {
+        (Some(Op::Unary(op)), Some(Op::Unary(ref_op))) => {
+            for v in vals {
+                let reference = ref_op(&v.to_reference(rp));
+                errors.push(ulp_error(&op(v).to_reference(rp), &reference, p));
+            }
+        }
+        (Some(Op::Binary(op)), Some(Op::Binary(ref_op))) => {
+            let (s1, s2) = vals.split_at(vals.len() / 2);
+            for (u, v) in s1.iter().zip(s2) {
+                let reference = ref_op(&u.to_reference(rp), &v.to_reference(rp));
+                errors.push(ulp_error(&op(u, v).to_reference(rp), &reference, p));
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    Accuracy {
+        max: errors.iter().cloned().fold(0.0, f64::max),
+        mean: errors.iter().sum::<f64>() / errors.len() as f64,
+    }
+}
+
+/// Returns the difference between `res` and `reference` in ULPs of a `p`-bit number.
+#[cfg(feature = "rug")]
+fn ulp_error(res: &Float, reference: &Float, p: usize) -> f64 {
+    if res.is_nan() || reference.is_nan() {
+        return if res.is_nan() && reference.is_nan() { 0.0 } else { f64::INFINITY };
+    }
+    if res.is_infinite() || reference.is_infinite() {
+        return if res == reference { 0.0 } else { f64::INFINITY };
+    }
+    match reference.get_exp() {
+        Some(e) => {
+            let d = Float::with_val(reference.prec(), res - reference).abs();
+            (d >> (e - p as i32)).to_f64()
+        }
+        None if res.is_zero() => 0.0,
+        None => f64::INFINITY,
+    }
+}
@@ -0,0 +1,170 @@
+//! Running a lib/task measurement in a child process of the same binary,
+//! so that it can be aborted after a timeout.
+//!
+//! The parent writes the operands to the child's stdin, one per line:
+//! the exponent, the sign and the mantissa words in hex.
+//! The child writes the outcome to stdout, one field per line.
+
+use std::io::{BufRead, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::number::Parts;
+use crate::report::{Measurement, Outcome};
+use crate::verify::Accuracy;
+use crate::Options;
+
+/// Interval of polling the child process for completion.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs the measurement of `lib` and `task` in a child process
+/// and returns `Outcome::Timeout` if it doesn't complete in `timeout`,
+/// or `Outcome::Failed` if it exits abnormally.
+pub(crate) fn run_in_child(lib: &str, task: &str, corpus: &[Parts], p: usize, opts: &Options, timeout: Duration) -> Outcome {
+    let exe = std::env::current_exe().expect("failed to locate the benchmark executable");
+    let mut cmd = Command::new(exe);
+    cmd.args(["--worker", "--lib", lib, "--task", task])
+        .args(["-n", &opts.n.to_string()])
+        .args(["--precision", &p.to_string()])
+        .args(["--target-time", &format!("{}s", opts.target_time.as_secs_f64())])
+        .args(["--max-time", &format!("{}s", opts.max_time.as_secs_f64())])
+        .args(["--warmup", &opts.warmup.as_millis().to_string()])
+        .args(["--statistic", opts.statistic.name()]);
+    if opts.verify {
+        cmd.arg("--verify");
+    }
+    if opts.self_check {
+        cmd.arg("--self-check");
+    }
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start the worker process");
+
+    let start = Instant::now();
+    let mut stdin = child.stdin.take().unwrap();
+    let input = write_corpus(corpus);
+    let writer = thread::spawn(move || {
+        // the child may be killed before it reads the whole input
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    loop {
+        if let Some(status) = child.try_wait().expect("failed to wait for the worker process") {
+            writer.join().unwrap();
+            let output = reader.join().unwrap();
+            if !status.success() {
+                eprintln!("worker process for {} {} failed: {}", lib, task, status);
+                return Outcome::Failed;
+            }
+            return read_outcome(&output, opts);
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            writer.join().unwrap();
+            reader.join().unwrap();
+            return Outcome::Timeout;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Reads operands from stdin in the child process.
+pub(crate) fn read_corpus() -> Vec<Parts> {
+    let stdin = std::io::stdin();
+    stdin
+        .lock()
+        .lines()
+        .map(|line| {
+            let line = line.expect("failed to read operands");
+            let mut fields = line.split(' ');
+            let exp = fields.next().unwrap().parse().expect("invalid operand");
+            let negative = fields.next().unwrap() == "-";
+            let mantissa = fields.map(|w| u64::from_str_radix(w, 16).expect("invalid operand")).collect();
+            Parts { mantissa, exp, negative }
+        })
+        .collect()
+}
+
+fn write_corpus(corpus: &[Parts]) -> String {
+    let mut ret = String::new();
+    for v in corpus {
+        ret += &format!("{} {}", v.exp, if v.negative { "-" } else { "+" });
+        for w in &v.mantissa {
+            ret += &format!(" {:x}", w);
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+/// Writes the outcome to stdout in the child process.
+pub(crate) fn write_outcome(outcome: &Outcome) {
+    match outcome {
+        Outcome::NotApplicable => println!("n/a"),
+        Outcome::Timeout => println!("timeout"),
+        Outcome::Failed => println!("failed"),
+        Outcome::Measured(m) => {
+            println!("ok");
+            println!("ops {}", m.ops);
+            println!("cold {}", m.cold.as_nanos());
+            let durations: Vec<String> = m.durations.iter().map(|d| d.as_nanos().to_string()).collect();
+            println!("durations {}", durations.join(" "));
+            if let Some(accuracy) = &m.accuracy {
+                println!("accuracy {} {}", accuracy.max, accuracy.mean);
+            }
+            if let Some(scaling) = m.scaling {
+                println!("scaling {}", scaling);
+            }
+        }
+    }
+}
+
+fn read_outcome(output: &str, opts: &Options) -> Outcome {
+    let mut lines = output.lines();
+    match lines.next() {
+        Some("n/a") => return Outcome::NotApplicable,
+        Some("timeout") => return Outcome::Timeout,
+        Some("failed") => return Outcome::Failed,
+        Some("ok") => {}
+        _ => panic!("invalid worker output"),
+    }
+
+    let mut ops = 0;
+    let mut cold = Duration::ZERO;
+    let mut durations = vec![];
+    let mut accuracy = None;
+    let mut scaling = None;
+    for line in lines {
+        let mut fields = line.split(' ');
+        let key = fields.next().unwrap();
+        let values: Vec<&str> = fields.collect();
+        match key {
+            "ops" => ops = values[0].parse().expect("invalid worker output"),
+            "cold" => cold = parse_nanos(values[0]),
+            "durations" => durations = values.iter().map(|v| parse_nanos(v)).collect(),
+            "accuracy" => {
+                accuracy = Some(Accuracy {
+                    max: values[0].parse().expect("invalid worker output"),
+                    mean: values[1].parse().expect("invalid worker output"),
+                })
+            }
+            "scaling" => scaling = Some(values[0].parse().expect("invalid worker output")),
+            _ => panic!("invalid worker output"),
+        }
+    }
+    Outcome::Measured(Measurement::new(durations, ops, cold, accuracy, scaling, opts.statistic))
+}
+
+fn parse_nanos(s: &str) -> Duration {
+    Duration::from_nanos(s.parse().expect("invalid worker output"))
+}